@@ -0,0 +1,380 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use proc_macro2::{Delimiter, Group, Literal, Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use quote::{quote, quote_spanned, ToTokens as _};
+use syn::{
+    parse::{ParseStream, Parser as _},
+    Block, Error, Expr, Ident, Item, Result,
+};
+
+use super::transform::{Registry, Transform};
+
+/// The state shared by all sequences of a single invocation.
+///
+/// Sequences are expanded in order, so anything declared by a sequence is visible to all of the following ones,
+/// up until the end of the enclosing scope.
+#[derive(Debug, Clone)]
+pub struct Context {
+    /// The bindings and aliases declared so far, innermost scope last.
+    scope_list: Vec<Scope>,
+    /// The third-party transformers available to this invocation.
+    registry: Registry,
+    /// The files read by this invocation, in order.
+    dependency_list: Vec<PathBuf>,
+    /// The environment variables read by this invocation, in order.
+    variable_list: Vec<String>,
+    /// The span of the first transformation that read a file or an environment variable, if any.
+    dependency_span: Option<Span>,
+}
+
+/// A single scope, holding everything declared within it by name.
+#[derive(Debug, Clone, Default)]
+struct Scope {
+    binding_map: HashMap<String, Binding>,
+    alias_map: HashMap<String, Alias>,
+}
+
+/// A named, already expanded [`TokenStream2`].
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     let #builder = [< foo Builder >]:case{pascal}:concatenate;
+///
+///     struct #builder; // Expands to `struct FooBuilder;`.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Binding {
+    /// The name the binding was declared with.
+    name: Ident,
+    /// The expanded value of the binding.
+    value: TokenStream2,
+}
+
+/// A named, already resolved list of transformations.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     transform snake_ident = flatten:case{snake}:concatenate;
+///
+///     fn [< GetValue >]:snake_ident() {} // Expands to `fn get_value() {}`.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Alias {
+    /// The name the alias was declared with.
+    name: Ident,
+    /// The transformations the alias stands for, containing no other aliases.
+    transform_list: Vec<Transform>,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new(Registry::default())
+    }
+}
+
+impl Context {
+    /// Create a new context, with the given third-party transformers available.
+    pub fn new(registry: Registry) -> Self {
+        Self {
+            scope_list: vec![Scope::default()],
+            registry,
+            dependency_list: Vec::new(),
+            variable_list: Vec::new(),
+            dependency_span: None,
+        }
+    }
+
+    /// Record a file the invocation depends on, read by the transformation at the given span.
+    pub fn depend(&mut self, path: PathBuf, span: Span) {
+        self.dependency_span.get_or_insert(span);
+
+        if !self.dependency_list.contains(&path) {
+            self.dependency_list.push(path);
+        }
+    }
+
+    /// Record an environment variable the invocation depends on, read by the transformation at the given span.
+    pub fn depend_env(&mut self, name: String, span: Span) {
+        self.dependency_span.get_or_insert(span);
+
+        if !self.variable_list.contains(&name) {
+            self.variable_list.push(name);
+        }
+    }
+
+    /// The span of the first transformation that read a file or an environment variable, if any.
+    pub fn dependency_span(&self) -> Option<Span> {
+        self.dependency_span
+    }
+
+    /// The constant that reads every dependency, if anything was read.
+    ///
+    /// As stable procedural macros cannot track files and environment variables on their own,
+    /// this relies on `include_bytes!` and `option_env!` doing so. The constant is unnamed, thus it may appear any number of times
+    /// within a module or a block, but not within an `impl` block.
+    pub fn tracking(&self) -> Option<TokenStream2> {
+        let span = self.dependency_span?;
+
+        let target_path_list = self
+            .dependency_list
+            .iter()
+            .map(|path| Literal::string(&path.to_string_lossy()));
+
+        let target_name_list = self.variable_list.iter().map(|name| Literal::string(name));
+
+        Some(quote_spanned! { span =>
+            const _: () = {
+                #(let _ = include_bytes!(#target_path_list);)*
+                #(let _ = option_env!(#target_name_list);)*
+            };
+        })
+    }
+
+    /// Finish the invocation, adding the [`Context::tracking`] constant to the output, so that it is rebuilt whenever a dependency changes.
+    ///
+    /// The placement of the constant depends on the output:
+    ///
+    /// - If the output is a list of items, the constant is placed at the start of the first function body or `const`/`static` initializer,
+    ///   which is valid both within a module and an `impl` block. Without either, it is appended to the items.
+    /// - Otherwise, if the output is an expression, it is wrapped into a block expression starting with the constant.
+    /// - Otherwise, if the output is a list of statements, the constant is prepended as another statement.
+    pub fn finish(&self, output: TokenStream2) -> Result<TokenStream2> {
+        let (Some(span), Some(tracking)) = (self.dependency_span, self.tracking()) else {
+            return Ok(output);
+        };
+
+        if let Ok(mut item_list) = split_items.parse2(output.clone()) {
+            let is_injected = item_list.iter_mut().any(|(item, tree_list)| inject(item, tree_list, &tracking));
+
+            let mut output = item_list
+                .into_iter()
+                .flat_map(|(_, tree_list)| tree_list)
+                .collect::<TokenStream2>();
+
+            if !is_injected {
+                output.extend(tracking);
+            }
+
+            return Ok(output);
+        }
+
+        if syn::parse2::<Expr>(output.clone()).is_ok() {
+            return Ok(quote_spanned!(span => { #tracking #output }));
+        }
+
+        if Block::parse_within.parse2(output.clone()).is_ok() {
+            return Ok(quote!(#tracking #output));
+        }
+
+        Err(Error::new(
+            span,
+            "this transformation reads a file or an environment variable, which is tracked through a constant, \
+             but the expansion is neither a list of items, an expression nor a list of statements",
+        ))
+    }
+
+    /// The third-party transformers available to this invocation.
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    /// Run the given closure within a new scope.
+    ///
+    /// All bindings and aliases declared within the closure are dropped once it returns.
+    pub fn scoped<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        self.scope_list.push(Scope::default());
+
+        let output = f(self);
+
+        self.scope_list.pop();
+
+        output
+    }
+
+    /// Look up a binding in all scopes, innermost first.
+    fn lookup(&self, target_repr: &str) -> Option<&Binding> {
+        self.scope_list
+            .iter()
+            .rev()
+            .find_map(|scope| scope.binding_map.get(target_repr))
+    }
+
+    /// Declare a new binding in the innermost scope.
+    ///
+    /// Bindings cannot be shadowed, declaring a name that is visible from the innermost scope is an error.
+    pub fn declare(&mut self, name: Ident, value: TokenStream2) -> Result<()> {
+        let target_repr = name.to_string();
+
+        if let Some(previous) = self.lookup(&target_repr) {
+            let mut error = Error::new(
+                name.span(),
+                format!("binding `#{target_repr}` is already declared, bindings cannot be shadowed"),
+            );
+
+            error.combine(Error::new(
+                previous.name.span(),
+                format!("binding `#{target_repr}` is previously declared here"),
+            ));
+
+            return Err(error);
+        }
+
+        self.scope_list
+            .last_mut()
+            .expect("the outermost scope is never dropped")
+            .binding_map
+            .insert(target_repr, Binding { name, value });
+
+        Ok(())
+    }
+
+    /// The value of a binding, if it is declared.
+    pub fn binding(&self, name: &Ident) -> Option<TokenStream2> {
+        self.lookup(&name.to_string()).map(|binding| binding.value.clone())
+    }
+
+    /// Resolve the value of a binding, reporting an error if it is not declared.
+    pub fn resolve(&self, name: &Ident) -> Result<TokenStream2> {
+        let target_repr = name.to_string();
+
+        self.lookup(&target_repr)
+            .map(|binding| binding.value.clone())
+            .ok_or_else(|| {
+                Error::new(
+                    name.span(),
+                    format!("unknown binding: `#{target_repr}`, declare it first with `let #{target_repr} = ...;`"),
+                )
+            })
+    }
+
+    /// Look up an alias in all scopes, innermost first.
+    fn lookup_alias(&self, target_repr: &str) -> Option<&Alias> {
+        self.scope_list
+            .iter()
+            .rev()
+            .find_map(|scope| scope.alias_map.get(target_repr))
+    }
+
+    /// Declare a new alias in the innermost scope.
+    ///
+    /// Like bindings, aliases cannot be shadowed.
+    pub fn define(&mut self, name: Ident, transform_list: Vec<Transform>) -> Result<()> {
+        let target_repr = name.to_string();
+
+        if let Some(previous) = self.lookup_alias(&target_repr) {
+            let mut error = Error::new(
+                name.span(),
+                format!("transform `{target_repr}` is already declared, transforms cannot be shadowed"),
+            );
+
+            error.combine(Error::new(
+                previous.name.span(),
+                format!("transform `{target_repr}` is previously declared here"),
+            ));
+
+            return Err(error);
+        }
+
+        self.scope_list
+            .last_mut()
+            .expect("the outermost scope is never dropped")
+            .alias_map
+            .insert(target_repr, Alias { name, transform_list });
+
+        Ok(())
+    }
+
+    /// Resolve the transformations an alias stands for, if it is declared.
+    pub fn alias(&self, name: &Ident) -> Option<Vec<Transform>> {
+        self.lookup_alias(&name.to_string())
+            .map(|alias| alias.transform_list.clone())
+    }
+}
+
+/// Split a list of items, keeping the token trees of each item as written.
+fn split_items(input: ParseStream) -> Result<Vec<(Item, Vec<TokenTree2>)>> {
+    let mut item_list = Vec::new();
+
+    while !input.is_empty() {
+        let mut cursor = input.cursor();
+
+        let item = input.parse::<Item>()?;
+
+        let end = input.cursor();
+
+        let mut tree_list = Vec::new();
+
+        while cursor != end {
+            let Some((tree, next)) = cursor.token_tree() else {
+                break;
+            };
+
+            tree_list.push(tree);
+
+            cursor = next;
+        }
+
+        item_list.push((item, tree_list));
+    }
+
+    Ok(item_list)
+}
+
+/// Place the tracking constant at the start of the body of a function, or the initializer of a `const` or `static`.
+///
+/// Returns whether the item has either.
+fn inject(item: &Item, tree_list: &mut Vec<TokenTree2>, tracking: &TokenStream2) -> bool {
+    match item {
+        // NOTE: The body of a function is its last token tree, unless the function stems from a `$item` fragment.
+        Item::Fn(_) => {
+            let Some(TokenTree2::Group(body)) = tree_list.last_mut() else {
+                return false;
+            };
+
+            if body.delimiter() != Delimiter::Brace {
+                return false;
+            }
+
+            let stream = body.stream();
+
+            let mut target_body = Group::new(Delimiter::Brace, quote!(#tracking #stream));
+
+            target_body.set_span(body.span());
+
+            *body = target_body;
+
+            true
+        }
+        Item::Const(item) => {
+            let mut target_item = item.clone();
+
+            let expr = &item.expr;
+
+            *target_item.expr = Expr::Verbatim(quote!({ #tracking #expr }));
+
+            *tree_list = target_item.into_token_stream().into_iter().collect();
+
+            true
+        }
+        Item::Static(item) => {
+            let mut target_item = item.clone();
+
+            let expr = &item.expr;
+
+            *target_item.expr = Expr::Verbatim(quote!({ #tracking #expr }));
+
+            *tree_list = target_item.into_token_stream().into_iter().collect();
+
+            true
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,62 @@
+pub mod context;
+pub mod sequence;
+pub mod transform;
+
+use context::Context;
+use proc_macro2::TokenStream as TokenStream2;
+use sequence::Sequence;
+use syn::{
+    parse::{Parse, ParseStream},
+    Result,
+};
+
+/// An embed is a collection of sequences.
+#[derive(Debug, Clone)]
+pub struct Embed(Vec<Sequence>);
+
+impl Embed {
+    /// Recursively expand the input token stream.
+    ///
+    /// The input is parsed as a whole, then its sequences are expanded in order.
+    ///
+    /// See [`Sequence::expand`] for the order in which a single sequence is evaluated,
+    /// and [`Context::finish`] for what is appended to the output.
+    #[inline]
+    pub fn recursively_expand(input: TokenStream2) -> Result<TokenStream2> {
+        let mut context = Context::default();
+
+        Self::expand_with(input, &mut context).and_then(|output| context.finish(output))
+    }
+
+    /// Recursively expand the input token stream, within an existing [`Context`].
+    #[inline]
+    pub fn expand_with(input: TokenStream2, context: &mut Context) -> Result<TokenStream2> {
+        syn::parse2::<Embed>(input).and_then(|embed| embed.expand(context))
+    }
+
+    /// Check whether the embed consists of exactly one block, along with its chain.
+    pub fn is_block(&self) -> bool {
+        matches!(self.0.as_slice(), [Sequence::Transform { .. }])
+    }
+
+    /// Expand the embed into a finished token stream.
+    #[inline]
+    pub fn expand(self, context: &mut Context) -> Result<TokenStream2> {
+        let Self(inner) = self;
+
+        inner
+            .into_iter()
+            .try_fold(TokenStream2::new(), |mut stream, sequence| {
+                stream.extend(sequence.expand(context)?);
+
+                Ok(stream)
+            })
+    }
+}
+
+impl Parse for Embed {
+    #[inline]
+    fn parse(input: ParseStream) -> Result<Self> {
+        Sequence::list(input).map(Self)
+    }
+}
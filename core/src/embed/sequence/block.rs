@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream as TokenStream2;
 use syn::{ parse::{Parse, ParseStream}, Result, Token};
 
-use crate::embed::transform::Segment;
+use crate::embed::{context::Context, transform::Segment};
 
 
 /// A singular transformation block.
@@ -20,13 +20,13 @@ pub struct Block {
 impl Block {
     /// Expand the transformation into a finished token stream.
     #[inline]
-    pub fn expand(self) -> Result<TokenStream2> {
+    pub fn expand(self, context: &mut Context) -> Result<TokenStream2> {
         let Self { segment_list, .. } = self;
 
         segment_list
             .into_iter()
             .try_fold(TokenStream2::new(), |mut stream, segment| {
-                stream.extend(segment.expand()?);
+                stream.extend(segment.expand(context)?);
 
                 Ok(stream)
             })
@@ -0,0 +1,234 @@
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use syn::{
+    braced,
+    parse::{Parse, ParseStream, Parser as _},
+    Error, LitInt, Result, Token,
+};
+
+use crate::embed::{context::Context, Embed};
+
+use super::Sequence;
+
+/// A conditional expansion.
+///
+/// Only the branch selected by the condition is expanded, within its own scope.
+///
+/// A conditional is marked with `#if` and `#else`, so that it cannot be confused with a regular `if` expression.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     let #count = [< $($variant)* >]:count;
+///
+///     #if #count > 255 {
+///         type Discriminant = u16;
+///     } #else {
+///         type Discriminant = u8;
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Conditional {
+    /// The condition selecting the branch.
+    condition: Condition,
+    /// The branch expanded if the condition holds.
+    then: Vec<Sequence>,
+    /// The branch expanded otherwise, `#else if` is represented as a single nested [`Conditional`].
+    otherwise: Vec<Sequence>,
+}
+
+/// A condition, either a single boolean operand or a comparison of two operands.
+#[derive(Debug, Clone)]
+pub struct Condition {
+    left: Operand,
+    comparison: Option<(Comparison, Operand)>,
+}
+
+/// A single operand of a [`Condition`], along with its span for error reporting.
+#[derive(Debug, Clone)]
+struct Operand {
+    span: Span,
+    sequence: Box<Sequence>,
+}
+
+/// A comparison between two operands.
+///
+/// Equality compares the operands token by token, while ordering requires both to be integer literals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Conditional {
+    /// Check whether the input starts a conditional, that is `#if`.
+    ///
+    /// As `if` is a keyword, `#if` is neither valid Rust nor a binding, so it cannot be confused with regular tokens.
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(Token![#]) && input.peek2(Token![if])
+    }
+
+    /// Parse a conditional following its `#`, that is starting with `if`.
+    fn parse_if(input: ParseStream) -> Result<Self> {
+        input.parse::<Token![if]>()?;
+
+        let condition = input.parse()?;
+
+        let then;
+
+        braced!(then in input);
+
+        let otherwise = if input.peek(Token![#]) && input.peek2(Token![else]) {
+            input.parse::<Token![#]>()?;
+            input.parse::<Token![else]>()?;
+
+            if input.peek(Token![if]) {
+                vec![Sequence::Conditional(Self::parse_if(input)?)]
+            } else {
+                let otherwise;
+
+                braced!(otherwise in input);
+
+                Sequence::list(&otherwise)?
+            }
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            condition,
+            then: Sequence::list(&then)?,
+            otherwise,
+        })
+    }
+
+    /// Expand the selected branch into a finished token stream.
+    pub fn expand(self, context: &mut Context) -> Result<TokenStream2> {
+        let Self {
+            condition,
+            then,
+            otherwise,
+        } = self;
+
+        let branch = if condition.evaluate(context)? {
+            then
+        } else {
+            otherwise
+        };
+
+        context.scoped(|context| Embed(branch).expand(context))
+    }
+}
+
+impl Condition {
+    /// Evaluate the condition.
+    pub fn evaluate(self, context: &mut Context) -> Result<bool> {
+        let Self { left, comparison } = self;
+
+        let Some((comparison, right)) = comparison else {
+            let span = left.span;
+
+            return boolean(left.sequence.expand(context)?, span);
+        };
+
+        let (left_span, right_span) = (left.span, right.span);
+
+        let (left, right) = (left.sequence.expand(context)?, right.sequence.expand(context)?);
+
+        match comparison {
+            Comparison::Eq => Ok(left.to_string() == right.to_string()),
+            Comparison::Ne => Ok(left.to_string() != right.to_string()),
+            ordering => {
+                let (left, right) = (integer(left, left_span)?, integer(right, right_span)?);
+
+                Ok(match ordering {
+                    Comparison::Lt => left < right,
+                    Comparison::Le => left <= right,
+                    Comparison::Gt => left > right,
+                    Comparison::Ge => left >= right,
+                    Comparison::Eq | Comparison::Ne => unreachable!("equality is handled above"),
+                })
+            }
+        }
+    }
+}
+
+/// Interpret an expanded operand as a boolean.
+fn boolean(input: TokenStream2, span: Span) -> Result<bool> {
+    match input.clone().into_iter().collect::<Vec<_>>().as_slice() {
+        [TokenTree2::Ident(ident)] if ident == "true" => Ok(true),
+        [TokenTree2::Ident(ident)] if ident == "false" => Ok(false),
+        _ => Err(Error::new(
+            span,
+            format!("expected the condition to expand to `true` or `false`, found `{input}`"),
+        )),
+    }
+}
+
+/// Interpret an expanded operand as a possibly negative integer.
+fn integer(input: TokenStream2, span: Span) -> Result<i128> {
+    let target_repr = input.to_string();
+
+    (|input: ParseStream| {
+        let is_negative = input.parse::<Option<Token![-]>>()?.is_some();
+
+        let value = input.parse::<LitInt>()?.base10_parse::<i128>()?;
+
+        Ok(if is_negative { -value } else { value })
+    })
+    .parse2(input)
+    .map_err(|_| Error::new(span, format!("expected the operand to expand to an integer literal, found `{target_repr}`")))
+}
+
+impl Parse for Conditional {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<Token![#]>()?;
+
+        Self::parse_if(input)
+    }
+}
+
+impl Parse for Condition {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let left = input.parse()?;
+
+        let comparison = if input.peek(Token![==]) {
+            input.parse::<Token![==]>().map(|_| Comparison::Eq)
+        } else if input.peek(Token![!=]) {
+            input.parse::<Token![!=]>().map(|_| Comparison::Ne)
+        } else if input.peek(Token![<=]) {
+            input.parse::<Token![<=]>().map(|_| Comparison::Le)
+        } else if input.peek(Token![>=]) {
+            input.parse::<Token![>=]>().map(|_| Comparison::Ge)
+        } else if input.peek(Token![<]) {
+            input.parse::<Token![<]>().map(|_| Comparison::Lt)
+        } else if input.peek(Token![>]) {
+            input.parse::<Token![>]>().map(|_| Comparison::Gt)
+        } else {
+            return Ok(Self {
+                left,
+                comparison: None,
+            });
+        }?;
+
+        Ok(Self {
+            left,
+            comparison: Some((comparison, input.parse()?)),
+        })
+    }
+}
+
+impl Parse for Operand {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            span: input.span(),
+            sequence: Box::new(Sequence::operand(input)?),
+        })
+    }
+}
@@ -0,0 +1,434 @@
+mod block;
+mod condition;
+mod repeat;
+
+pub use block::Block;
+pub use condition::{Comparison, Condition, Conditional};
+pub use repeat::Repeat;
+
+use proc_macro2::Delimiter;
+use proc_macro2::Group;
+use proc_macro2::Punct;
+use proc_macro2::Spacing;
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::TokenTree as TokenTree2;
+use quote::{quote, ToTokens as _};
+use syn::token::{Brace, Bracket};
+use syn::Error;
+use syn::Ident;
+use syn::Lit;
+use syn::Token;
+use syn::{
+    parse::{Parse, ParseStream},
+    Result,
+};
+
+use super::{
+    context::Context,
+    transform::{TransformChain, TransformKind},
+    Embed,
+};
+
+/// A single sequence in an embed.
+///
+/// This can either eb a sequence of tokens or a transformation.
+///
+/// See [`Sequence::TokenTree`], [`Sequence::Transform`], [`Sequence::Let`], [`Sequence::Alias`] and [`Sequence::Repeat`] for more information.
+#[derive(Debug, Clone)]
+pub enum Sequence {
+    /// A single token tree, emitted as-is.
+    TokenTree(TokenTree2),
+    /// A group, whose contents are expanded on their own.
+    Group(Group),
+    /// A transformed block.
+    Transform {
+        block: Block,
+        /// A [`Block`] finalizer.
+        ///
+        /// A finalizer is a single transformation that is applied to the entire block.
+        ///
+        /// # Example
+        ///
+        /// ```rust, ignore
+        /// embed! {
+        ///    struct [< ((HELLO)):flatten:case{lower} [world]:ungroup:case{upper} >]:concatenate; // Expands to `helloWORLD
+        ///    struct [< ((HELLO)):flatten:case{lower} [world]:ungroup:case{upper} >]:separated{_}; // Expands to `hello_WORLD`
+        /// }
+        chain: Option<TransformChain>,
+    },
+    /// A binding declaration, which expands to nothing.
+    ///
+    /// The value is expanded once, and can be referenced by all of the following sequences.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// embed! {
+    ///    let #builder = [< $name:case{pascal} Builder >]:concatenate;
+    ///
+    ///    struct #builder;
+    ///    impl #builder {}
+    /// }
+    /// ```
+    Let {
+        name: Ident,
+        value: Vec<Sequence>,
+    },
+    /// A reference to a binding, e.g. `#builder`.
+    ///
+    /// Only names declared by `let #name = ...;` or a loop are resolved, any other `#name` is emitted as-is,
+    /// so that it reaches nested macros such as `quote!`.
+    Binding {
+        /// The leading `#`, emitted along with the name if no binding of that name is declared.
+        pound: Punct,
+        name: Ident,
+        /// Whether an undeclared name is an error, as it is for the operands of control sequences.
+        is_required: bool,
+    },
+    /// An alias declaration, which expands to nothing.
+    ///
+    /// The chain is resolved once, and can be referred to by name by all of the following chains.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// embed! {
+    ///    transform snake_ident = flatten:case{snake}:concatenate;
+    ///
+    ///    fn [< $getter >]:snake_ident() {}
+    /// }
+    /// ```
+    Alias {
+        name: Ident,
+        chain: TransformChain,
+    },
+    /// A loop over the token trees of a list.
+    ///
+    /// See [`Repeat`].
+    Repeat(Repeat),
+    /// A conditional expansion.
+    ///
+    /// See [`Conditional`].
+    Conditional(Conditional),
+}
+
+impl Sequence {
+    /// Parse a list of [`Sequence`].
+    #[inline]
+    pub fn list(input: ParseStream) -> Result<Vec<Self>> {
+        let mut sequence_list = Vec::new();
+
+        Ok({
+            loop {
+                if input.is_empty() {
+                    break sequence_list;
+                }
+
+                if Self::peek_head(input) {
+                    sequence_list.push(Self::TokenTree(input.parse()?));
+
+                    // NOTE: The head ends at the body, the first brace group that is not taken as the arguments of a chain.
+                    while !input.is_empty() && !input.peek(Brace) {
+                        if !input.peek(Bracket) {
+                            sequence_list.push(input.parse()?);
+
+                            continue;
+                        }
+
+                        let TokenTree2::Group(inner) = input.parse::<TokenTree2>()? else {
+                            unreachable!("peeked for a bracket, but did not find a group");
+                        };
+
+                        sequence_list.push(Self::bracket(inner, input, true)?);
+                    }
+
+                    continue;
+                }
+
+                sequence_list.push(input.parse()?);
+            }
+        })
+    }
+
+    /// Parse a bracket group, either escaped, a regular group, or a block along with its chain.
+    ///
+    /// Within the head of a regular `if`, `while`, `match` or `for`, see [`TransformChain::parse_head`].
+    fn bracket(inner: Group, input: ParseStream, is_head: bool) -> Result<Self> {
+        if let Some(unescaped) = Self::unescape(&inner) {
+            return Ok(Self::TokenTree(TokenTree2::Group(unescaped)));
+        }
+
+        if !Self::is_block(&inner) {
+            return Ok(Self::Group(inner));
+        }
+
+        let block = syn::parse2::<Block>(inner.stream()).map_err(|mut error| {
+            error.combine(Error::new(
+                inner.span(),
+                "this bracket group is parsed as a block, as it starts with `<` and ends with `>`\n\
+                 if it is meant to be emitted as-is, escape it as `[<< ... >>]`",
+            ));
+
+            error
+        })?;
+
+        let chain = TransformChain::peek(input)
+            .then(|| {
+                if is_head {
+                    TransformChain::parse_head(input)
+                } else {
+                    input.parse::<TransformChain>()
+                }
+            })
+            .transpose()?;
+
+        Ok(Self::Transform { block, chain })
+    }
+
+    /// Parse a single operand of a control sequence, either a block, a binding or a literal.
+    ///
+    /// Unlike a regular sequence, an operand cannot have a chain attached, as it would take the following body as its arguments.
+    pub fn operand(input: ParseStream) -> Result<Self> {
+        let operand = if input.peek(Token![#]) && input.peek2(Ident) {
+            Self::Binding {
+                pound: input.parse()?,
+                name: input.parse()?,
+                is_required: true,
+            }
+        } else if input.peek(Bracket) || input.peek(Lit) {
+            // NOTE: The operand is parsed on its own, so that a trailing chain is not consumed.
+            syn::parse2(input.parse::<TokenTree2>()?.into_token_stream())?
+        } else {
+            return Err(input.error("expected a block, a binding or a literal"));
+        };
+
+        if TransformChain::peek(input) {
+            return Err(Error::new(
+                input.span(),
+                "a chain cannot be attached here, as it would take the following body as its arguments\n\
+                 move the chain into a block instead: `[< [< .. >]:chain >]`",
+            ));
+        }
+
+        Ok(operand)
+    }
+
+    /// Check whether the input starts a regular `if`, `while`, `match` or `for`, whose head is followed by a brace group.
+    ///
+    /// Loops over bindings, see [`Repeat`], are not regular.
+    fn peek_head(input: ParseStream) -> bool {
+        input.peek(Token![if])
+            || input.peek(Token![while])
+            || input.peek(Token![match])
+            || (input.peek(Token![for]) && !Repeat::peek(input))
+    }
+
+    /// Check whether the input starts an alias declaration, that is `transform name =`.
+    ///
+    /// Two identifiers followed by `=` are not valid Rust, so these cannot be confused with regular tokens.
+    fn peek_alias(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        fork.parse::<Ident>().is_ok_and(|ident| ident == "transform")
+            && fork.parse::<Ident>().is_ok()
+            && fork.peek(Token![=])
+            && !fork.peek(Token![==])
+            && !fork.peek(Token![=>])
+    }
+
+    /// Check whether a group is a block, that is a bracket group starting with `<` and ending with `>`.
+    ///
+    /// Escaped groups are not blocks, see [`Sequence::unescape`].
+    pub fn is_block(group: &Group) -> bool {
+        let mut iter = group.stream().into_iter();
+
+        let is_delimited = matches!(
+            (iter.next(), iter.last()),
+            (Some(TokenTree2::Punct(left)), Some(TokenTree2::Punct(right)))
+                if left.as_char() == '<' && right.as_char() == '>'
+        );
+
+        group.delimiter() == Delimiter::Bracket && is_delimited && Self::unescape(group).is_none()
+    }
+
+    /// Unescape a bracket group of the form `[<< ... >>]` into `[ ... ]`.
+    ///
+    /// Returns [`None`] if the group is not escaped, both markers must be joint punctuation.
+    pub fn unescape(group: &Group) -> Option<Group> {
+        if group.delimiter() != Delimiter::Bracket {
+            return None;
+        }
+
+        let tokens = group.stream().into_iter().collect::<Vec<_>>();
+
+        let is_marker = |tree: &TokenTree2, target: char, joint: bool| {
+            matches!(tree, TokenTree2::Punct(punct) if punct.as_char() == target && (!joint || punct.spacing() == Spacing::Joint))
+        };
+
+        match tokens.as_slice() {
+            [first, second, inner @ .., second_last, last]
+                if is_marker(first, '<', true)
+                    && is_marker(second, '<', false)
+                    && is_marker(second_last, '>', true)
+                    && is_marker(last, '>', false) =>
+            {
+                let mut unescaped = Group::new(Delimiter::Bracket, inner.iter().cloned().collect());
+
+                unescaped.set_span(group.span());
+
+                Some(unescaped)
+            }
+            _ => None,
+        }
+    }
+
+    /// Expand the sequence into a finished token stream.
+    ///
+    /// Evaluation is strictly inside-out and left-to-right:
+    ///
+    /// 1. The contents of a group are expanded when the group is reached.
+    /// 2. The segments of a [`Block`] are expanded in order, with any nested block being fully expanded first.
+    /// 3. The chain attached to a segment or block is applied to its expanded output.
+    /// 4. The arguments of each transformation are expanded right before it is applied, after its input.
+    #[inline]
+    pub fn expand(self, context: &mut Context) -> Result<TokenStream2> {
+        match self {
+            Self::TokenTree(inner) => Ok(inner.into_token_stream()),
+            // NOTE: Anything declared within a group is scoped to it, like the items and variables of a Rust block.
+            Self::Group(group) => {
+                let stream = context.scoped(|context| Embed::expand_with(group.stream(), context))?;
+
+                let mut target_group = Group::new(group.delimiter(), stream);
+
+                target_group.set_span(group.span());
+
+                Ok(TokenTree2::Group(target_group).into_token_stream())
+            }
+            Self::Transform { block, chain } => {
+                let block = block.expand(context)?;
+
+                if let Some(chain) = chain {
+                    chain.expand(block, context)
+                } else {
+                    Ok(block)
+                }
+            }
+            Self::Let { name, value } => {
+                let value = Embed(value).expand(context)?;
+
+                context.declare(name, value).map(|_| TokenStream2::new())
+            }
+            Self::Binding {
+                pound,
+                name,
+                is_required,
+            } => match context.binding(&name) {
+                Some(value) => Ok(value),
+                None if is_required => context.resolve(&name),
+                None => Ok(quote!(#pound #name)),
+            },
+            Self::Alias { name, chain } => {
+                if TransformKind::is_builtin(&name.to_string()) {
+                    return Err(Error::new(
+                        name.span(),
+                        format!("transform `{name}` shadows a built-in transform, choose a different name"),
+                    ));
+                }
+
+                if context.registry().contains(&name.to_string()) {
+                    return Err(Error::new(
+                        name.span(),
+                        format!("transform `{name}` shadows a registered transform, choose a different name"),
+                    ));
+                }
+
+                let transform_list = chain.resolve(context)?;
+
+                context.define(name, transform_list).map(|_| TokenStream2::new())
+            }
+            Self::Repeat(repeat) => repeat.expand(context),
+            Self::Conditional(conditional) => conditional.expand(context),
+        }
+    }
+}
+
+impl Parse for Sequence {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // NOTE: `let #` is not valid Rust, so it cannot be confused with regular tokens.
+        if input.peek(Token![let]) && input.peek2(Token![#]) {
+            input.parse::<Token![let]>()?;
+            input.parse::<Token![#]>()?;
+
+            let name = input.parse::<Ident>()?;
+
+            input.parse::<Token![=]>()?;
+
+            let mut value = Vec::new();
+
+            while !input.peek(Token![;]) {
+                if input.is_empty() {
+                    return Err(input.error(format!("expected `;` after the value of binding `#{name}`")));
+                }
+
+                value.push(input.parse()?);
+            }
+
+            input.parse::<Token![;]>()?;
+
+            return Ok(Self::Let { name, value });
+        }
+
+        if Self::peek_alias(input) {
+            input.parse::<Ident>()?;
+
+            let name = input.parse::<Ident>()?;
+
+            input.parse::<Token![=]>()?;
+
+            let mut chain = TokenStream2::new();
+
+            // NOTE: The leading colon of the chain is optional, as there is no target to separate it from.
+            if !input.peek(Token![:]) {
+                chain.extend(Punct::new(':', Spacing::Alone).into_token_stream());
+            }
+
+            while !input.peek(Token![;]) {
+                if input.is_empty() {
+                    return Err(input.error(format!("expected `;` after the chain of transform `{name}`")));
+                }
+
+                chain.extend(std::iter::once(input.parse::<TokenTree2>()?));
+            }
+
+            input.parse::<Token![;]>()?;
+
+            return Ok(Self::Alias {
+                name,
+                chain: syn::parse2(chain)?,
+            });
+        }
+
+        if Repeat::peek(input) {
+            return input.parse().map(Self::Repeat);
+        }
+
+        if Conditional::peek(input) {
+            return input.parse().map(Self::Conditional);
+        }
+
+        // NOTE: Unlike `let #`, `#ident` is valid within nested macros such as `quote!`, thus it is only resolved if declared.
+        if input.peek(Token![#]) && input.peek2(Ident) {
+            return Ok(Self::Binding {
+                pound: input.parse()?,
+                name: input.parse()?,
+                is_required: false,
+            });
+        }
+
+        match input.parse::<TokenTree2>()? {
+            TokenTree2::Group(inner) if inner.delimiter() == Delimiter::Bracket => Self::bracket(inner, input, false),
+            TokenTree2::Group(inner) => Ok(Self::Group(inner)),
+            target_tree => Ok(Self::TokenTree(target_tree)),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use proc_macro2::{Literal, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use quote::ToTokens as _;
+use syn::{
+    braced, parenthesized,
+    parse::{Parse, ParseStream, Parser as _},
+    token::Paren,
+    Ident, Result, Token,
+};
+
+use crate::embed::{context::Context, Embed};
+
+use super::Sequence;
+
+/// A loop over the token trees of a list.
+///
+/// The body is expanded once per token tree in the list, with the token tree bound to the item binding,
+/// and optionally its position bound to the index binding.
+///
+/// Each iteration is expanded within its own scope, thus bindings declared in the body are not visible outside of it.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     for (#index, #variant) in [< Red Green Blue >] {
+///         const [< #variant:case{upper} >]:concatenate : &u8 = &#index; // Expands to `const RED: &u8 = &0;` and so on.
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Repeat {
+    /// The binding for the position of the current token tree, if any.
+    index: Option<Ident>,
+    /// The binding for the current token tree.
+    item: Ident,
+    /// The list to iterate over, see [`Sequence::operand`].
+    list: Box<Sequence>,
+    /// The body to expand for every token tree.
+    body: Vec<Sequence>,
+}
+
+impl Repeat {
+    /// Check whether the input starts a loop, as opposed to a regular `for` loop or `impl .. for` item.
+    ///
+    /// Neither `for #item in` nor `for (#index, #item) in` are valid Rust, so these cannot be confused with regular tokens,
+    /// whereas a regular `for (index, item) in` is left as is.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        let is_binding = |input: ParseStream| input.parse::<Token![#]>().and_then(|_| input.parse::<Ident>()).is_ok();
+
+        // NOTE: A regular `for (a, b) in` is valid Rust, thus the parenthesized form must hold exactly two bindings.
+        let is_pair = |input: ParseStream| match input.parse::<TokenTree2>() {
+            Ok(TokenTree2::Group(group)) => (|input: ParseStream| -> Result<bool> {
+                let is_pair = is_binding(input)
+                    && input.parse::<Token![,]>().is_ok()
+                    && is_binding(input)
+                    && input.is_empty();
+
+                Ok(is_pair)
+            })
+            .parse2(group.stream())
+            .unwrap_or(false),
+            _ => false,
+        };
+
+        fork.parse::<Token![for]>().is_ok()
+            && if fork.peek(Paren) {
+                is_pair(&fork)
+            } else {
+                is_binding(&fork)
+            }
+            && fork.peek(Token![in])
+    }
+
+    /// Expand the loop into a finished token stream.
+    pub fn expand(self, context: &mut Context) -> Result<TokenStream2> {
+        let Self {
+            index,
+            item,
+            list,
+            body,
+        } = self;
+
+        list.expand(context)?
+            .into_iter()
+            .enumerate()
+            .try_fold(TokenStream2::new(), |mut stream, (position, target_tree)| {
+                let output = context.scoped(|context| {
+                    if let Some(index) = &index {
+                        let mut target_index = Literal::usize_unsuffixed(position);
+
+                        target_index.set_span(target_tree.span());
+
+                        context.declare(index.clone(), target_index.into_token_stream())?;
+                    }
+
+                    context.declare(item.clone(), target_tree.into_token_stream())?;
+
+                    Embed(body.clone()).expand(context)
+                })?;
+
+                stream.extend(output);
+
+                Ok(stream)
+            })
+    }
+}
+
+impl Parse for Repeat {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<Token![for]>()?;
+
+        let (index, item) = if input.peek(Paren) {
+            let content;
+
+            parenthesized!(content in input);
+
+            content.parse::<Token![#]>()?;
+
+            let index = content.parse::<Ident>()?;
+
+            content.parse::<Token![,]>()?;
+            content.parse::<Token![#]>()?;
+
+            (Some(index), content.parse::<Ident>()?)
+        } else {
+            input.parse::<Token![#]>()?;
+
+            (None, input.parse::<Ident>()?)
+        };
+
+        input.parse::<Token![in]>()?;
+
+        let list = Sequence::operand(input)?;
+
+        let body;
+
+        braced!(body in input);
+
+        Ok(Self {
+            index,
+            item,
+            list: Box::new(list),
+            body: Sequence::list(&body)?,
+        })
+    }
+}
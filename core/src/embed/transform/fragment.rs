@@ -0,0 +1,102 @@
+use phf::{phf_map, Map};
+use proc_macro2::{TokenStream as TokenStream2, TokenTree as TokenTree2};
+use quote::ToTokens;
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream},
+    Error, Expr, Ident, Item, Lifetime, Lit, Pat, Path, Result, Type,
+};
+
+static RECOGNIZED_FRAGMENTS: Map<&str, Fragment> = phf_map! {
+    "ident" => Fragment::Ident,
+    "literal" => Fragment::Literal,
+    "lifetime" => Fragment::Lifetime,
+    "ty" => Fragment::Ty,
+    "expr" => Fragment::Expr,
+    "path" => Fragment::Path,
+    "pat" => Fragment::Pat,
+    "item" => Fragment::Item,
+    "tt" => Fragment::Tt,
+};
+
+/// A kind of syntax node, akin to the fragment specifiers of `macro_rules!`.
+///
+/// Parsing is delegated to [`syn`], thus a fragment accepts exactly what the corresponding [`syn`] node does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fragment {
+    /// An identifier, including keywords, see [`Ident`].
+    Ident,
+    /// A literal, see [`Lit`].
+    Literal,
+    /// A lifetime, see [`Lifetime`].
+    Lifetime,
+    /// A type, see [`Type`].
+    Ty,
+    /// An expression, see [`Expr`].
+    Expr,
+    /// A path, see [`Path`].
+    Path,
+    /// A pattern, including top-level alternatives, see [`Pat`].
+    Pat,
+    /// An item, see [`Item`].
+    Item,
+    /// A single token tree, see [`TokenTree2`].
+    Tt,
+}
+
+impl Fragment {
+    /// Parse a single fragment from the input, returning its tokens.
+    pub fn parse_from(self, input: ParseStream) -> Result<TokenStream2> {
+        Ok(match self {
+            Self::Ident => Ident::parse_any(input)?.into_token_stream(),
+            Self::Literal => input.parse::<Lit>()?.into_token_stream(),
+            Self::Lifetime => input.parse::<Lifetime>()?.into_token_stream(),
+            Self::Ty => input.parse::<Type>()?.into_token_stream(),
+            Self::Expr => input.parse::<Expr>()?.into_token_stream(),
+            Self::Path => input.parse::<Path>()?.into_token_stream(),
+            Self::Pat => Pat::parse_multi_with_leading_vert(input)?.into_token_stream(),
+            Self::Item => input.parse::<Item>()?.into_token_stream(),
+            Self::Tt => input.parse::<TokenTree2>()?.into_token_stream(),
+        })
+    }
+
+    /// Describe the fragment, for use in error messages.
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::Ident => "an identifier",
+            Self::Literal => "a literal",
+            Self::Lifetime => "a lifetime",
+            Self::Ty => "a type",
+            Self::Expr => "an expression",
+            Self::Path => "a path",
+            Self::Pat => "a pattern",
+            Self::Item => "an item",
+            Self::Tt => "a single token tree",
+        }
+    }
+}
+
+impl Parse for Fragment {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let target_ident = input.parse::<Ident>()?;
+
+        let target_repr = target_ident.to_string();
+
+        RECOGNIZED_FRAGMENTS
+            .get(target_repr.as_str())
+            .copied()
+            .ok_or_else(|| {
+                Error::new(
+                    target_ident.span(),
+                    format!(
+                        "unknown fragment: `{target_repr}`, valid fragments are: {fragments}",
+                        fragments = RECOGNIZED_FRAGMENTS
+                            .keys()
+                            .copied()
+                            .collect::<Vec<&str>>()
+                            .join(" ")
+                    ),
+                )
+            })
+    }
+}
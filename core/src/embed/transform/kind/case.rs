@@ -1,15 +1,17 @@
 use std::str::FromStr;
 
 use proc_macro2::{
-    Delimiter as Delimiter2, Group as Group2, TokenStream as TokenStream2, TokenTree as TokenTree2,
+    Group as Group2, TokenStream as TokenStream2, TokenTree as TokenTree2,
 };
 use quote::ToTokens;
-use syn::{Ident, Lit};
+use syn::{ext::IdentExt as _, Ident, Lit};
 
 use convert_case::{Case, Casing};
 
 use crate::embed::transform::Transformate;
 
+use super::span::respan;
+
 
 /// A transformation that changes the case of the target [`TokenStream2`].
 #[derive(Debug, Clone)]
@@ -45,18 +47,32 @@ impl Transformate for TransformCase {
             .into_iter()
             .try_fold(TokenStream2::new(), |mut acc, target_tree| {
                 let target_output = match target_tree {
+                    // NOTE: Re-lexed tokens are spanned at the call site, so they are pointed back at their source.
                     TokenTree2::Literal(target_lit) => match syn::parse2::<Lit>(target_lit.into_token_stream())? {
-                        Lit::Str(inner) => {
-                            TokenStream2::from_str(inner.value().to_case(*case).as_str())?
-                        },
-                        Lit::Bool(lit) => {
-                            TokenStream2::from_str(lit.value.to_string().to_case(*case).as_str())?
-                        },
-
-                        lit @ _ => lit.into_token_stream(),
+                        Lit::Str(inner) => respan(
+                            TokenStream2::from_str(inner.value().to_case(*case).as_str())?,
+                            inner.span(),
+                        ),
+                        Lit::Bool(lit) => respan(
+                            TokenStream2::from_str(lit.value.to_string().to_case(*case).as_str())?,
+                            lit.span,
+                        ),
+
+                        lit => lit.into_token_stream(),
                     },
                     TokenTree2::Ident(target_ident) => {
-                        TokenStream2::from_str(target_ident.to_string().to_case(*case).as_str())?
+                        let target_repr = target_ident.unraw().to_string().to_case(*case);
+
+                        // NOTE: A raw identifier stays raw, as it may still be a keyword in its new case, e.g. `r#Type` in snake case,
+                        // unless it cannot be raw at all, e.g. `self`.
+                        let target_stream = if target_ident.to_string().starts_with("r#") {
+                            TokenStream2::from_str(&format!("r#{target_repr}"))
+                                .or_else(|_| TokenStream2::from_str(&target_repr))?
+                        } else {
+                            TokenStream2::from_str(&target_repr)?
+                        };
+
+                        respan(target_stream, target_ident.span())
                     }
                     TokenTree2::Group(group) => group
                         .stream()
@@ -69,7 +85,7 @@ impl Transformate for TransformCase {
                             })
                         })
                         .map(|a| {
-                            let mut new_group = Group2::new(Delimiter2::Brace, a);
+                            let mut new_group = Group2::new(group.delimiter(), a);
 
                             new_group.set_span(group.span());
 
@@ -78,7 +94,7 @@ impl Transformate for TransformCase {
                         .map(TokenTree2::Group)
                         .map(ToTokens::into_token_stream)?,
 
-                    target_tree @ _ => target_tree.into_token_stream(),
+                    target_tree => target_tree.into_token_stream(),
                 };
 
                 acc.extend(target_output);
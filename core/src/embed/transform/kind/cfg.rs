@@ -0,0 +1,233 @@
+use std::env;
+
+use phf::{phf_map, Map};
+use proc_macro2::{Delimiter, Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use syn::{
+    parse::{ParseStream, Parser as _},
+    Error, Ident, Lit, LitBool,
+};
+
+use crate::embed::transform::Transformate;
+
+use super::predicate::boolean;
+
+static RECOGNIZED_KEYS: Map<&str, Key> = phf_map! {
+    "feature" => Key::Feature,
+    "target_arch" => Key::Cfg("CARGO_CFG_TARGET_ARCH"),
+    "target_os" => Key::Cfg("CARGO_CFG_TARGET_OS"),
+    "target_family" => Key::Cfg("CARGO_CFG_TARGET_FAMILY"),
+    "target_env" => Key::Cfg("CARGO_CFG_TARGET_ENV"),
+    "target_vendor" => Key::Cfg("CARGO_CFG_TARGET_VENDOR"),
+    "target_endian" => Key::Cfg("CARGO_CFG_TARGET_ENDIAN"),
+    "target_pointer_width" => Key::Cfg("CARGO_CFG_TARGET_POINTER_WIDTH"),
+    "target_feature" => Key::Cfg("CARGO_CFG_TARGET_FEATURE"),
+};
+
+/// A predicate that checks whether a Cargo feature is enabled, or a `cfg` option has a given value.
+///
+/// Like all predicates, this appends either `true` or `false` to the target [`TokenStream2`], to be used in a condition.
+///
+/// The predicates follow the environment of build scripts, that is `CARGO_FEATURE_<name>` and `CARGO_CFG_<key>`.
+/// As Cargo sets these for build scripts only, a build script has to forward them to the crate, e.g. with
+/// `cargo:rustc-env=CARGO_FEATURE_SERDE=1`, see the README for a complete build script. The variables are not tracked,
+/// as Cargo already rebuilds the crate whenever the output of its build script changes, thus a predicate may appear anywhere.
+///
+/// As `CARGO_CFG_TARGET_ARCH` is set for every target, a predicate reports an error if it is missing,
+/// rather than silently evaluating to `false` without a forwarding build script.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     #if [< :feature{"serde"} >] {
+///         mod serde_impl;
+///     }
+///
+///     struct [< Foo _ [< :target_arch{"x86_64"} >] >]:concatenate; // Expands to `struct Foo_true;` on `x86_64`.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformCfg;
+
+/// A transformation that keeps the target [`TokenStream2`] if the given condition holds, and drops it otherwise.
+///
+/// The condition is either `true` or `false`, e.g. the result of a predicate, or a predicate of [`TransformCfg`] as written.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     struct [< Foo [< _serde >]:when{feature{"serde"}} >]:concatenate; // Expands to `struct Foo_serde;` if `serde` is enabled.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformWhen;
+
+/// The key of a [`TransformCfg`] predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    /// A Cargo feature, set if `CARGO_FEATURE_<name>` is present.
+    Feature,
+    /// A `cfg` option, holding a comma-separated list of values within the given variable.
+    Cfg(&'static str),
+}
+
+/// The parsed arguments of a [`TransformCfg`].
+#[derive(Debug, Clone)]
+pub struct CfgArgs {
+    key: Key,
+    /// The value to check for, e.g. the feature name.
+    value: String,
+    /// The span of the value, as written.
+    span: Span,
+}
+
+/// The parsed arguments of a [`TransformWhen`].
+#[derive(Debug, Clone)]
+pub enum WhenArgs {
+    /// An already evaluated condition.
+    Boolean(bool),
+    /// A predicate of [`TransformCfg`], evaluated when applied.
+    Cfg(CfgArgs),
+}
+
+/// The variable that is set for every target, thus missing only if the build script environment is not forwarded.
+const FORWARDED_VARIABLE: &str = "CARGO_CFG_TARGET_ARCH";
+
+impl CfgArgs {
+    /// Parse the value of the predicate named by the given identifier.
+    fn new(name: &Ident, args: TokenStream2) -> Result<Self, Error> {
+        let target_repr = name.to_string();
+
+        let key = RECOGNIZED_KEYS
+            .get(target_repr.as_str())
+            .copied()
+            .ok_or_else(|| {
+                Error::new(
+                    name.span(),
+                    format!(
+                        "unknown predicate: `{target_repr}`, valid predicates are: {keys}",
+                        keys = RECOGNIZED_KEYS
+                            .keys()
+                            .copied()
+                            .collect::<Vec<&str>>()
+                            .join(" ")
+                    ),
+                )
+            })?;
+
+        let (value, span) = (|input: ParseStream| {
+            if input.peek(Ident) {
+                let target_ident = input.parse::<Ident>()?;
+
+                return Ok((target_ident.to_string(), target_ident.span()));
+            }
+
+            match input.parse::<Lit>()? {
+                Lit::Str(target_literal) => Ok((target_literal.value(), target_literal.span())),
+                Lit::Int(target_literal) => Ok((
+                    target_literal.base10_digits().to_owned(),
+                    target_literal.span(),
+                )),
+                target_literal => Err(Error::new(
+                    target_literal.span(),
+                    "expected a string literal, e.g. `feature{\"serde\"}`",
+                )),
+            }
+        })
+        .parse2(args)?;
+
+        Ok(Self { key, value, span })
+    }
+
+    /// The environment variable the predicate is evaluated against.
+    pub fn variable(&self) -> String {
+        match self.key {
+            Key::Feature => format!(
+                "CARGO_FEATURE_{}",
+                self.value.to_uppercase().replace('-', "_")
+            ),
+            Key::Cfg(variable) => variable.to_owned(),
+        }
+    }
+
+    /// Evaluate the predicate against the environment, reporting an error if the build script environment is not forwarded.
+    pub fn holds(&self) -> Result<bool, Error> {
+        if env::var_os(FORWARDED_VARIABLE).is_none() {
+            return Err(Error::new(
+                self.span,
+                format!(
+                    "`{FORWARDED_VARIABLE}` is not set, thus the environment of build scripts is not forwarded to the crate\n\
+                     consider forwarding the `CARGO_FEATURE_*` and `CARGO_CFG_*` variables from a build script, \
+                     e.g. with `cargo:rustc-env={FORWARDED_VARIABLE}=..`"
+                ),
+            ));
+        }
+
+        Ok(match (self.key, env::var(self.variable())) {
+            (Key::Feature, value) => value.is_ok(),
+            (Key::Cfg(_), Ok(value_list)) => value_list.split(',').any(|value| value == self.value),
+            (Key::Cfg(_), Err(_)) => false,
+        })
+    }
+}
+
+impl Transformate for TransformCfg {
+    type Args = CfgArgs;
+
+    /// Parse a predicate as written, e.g. `feature{"serde"}`, as the name of the predicate is the name of the transformation.
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        WhenArgs::cfg.parse2(args)
+    }
+
+    fn apply(mut input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        input.extend(boolean(args.holds()?, args.span));
+
+        Ok(input)
+    }
+}
+
+impl WhenArgs {
+    /// Parse a predicate as written, e.g. `feature{"serde"}`.
+    fn cfg(input: ParseStream) -> Result<CfgArgs, Error> {
+        let target_ident = input.parse::<Ident>()?;
+
+        match input.parse::<TokenTree2>()? {
+            TokenTree2::Group(group) if group.delimiter() == Delimiter::Brace => {
+                CfgArgs::new(&target_ident, group.stream())
+            }
+            tree => Err(Error::new(
+                tree.span(),
+                format!(
+                    "expected the arguments of `{target_ident}`, e.g. `{target_ident}{{\"..\"}}`"
+                ),
+            )),
+        }
+    }
+}
+
+impl Transformate for TransformWhen {
+    type Args = WhenArgs;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        (|input: ParseStream| {
+            if input.peek(LitBool) {
+                return Ok(WhenArgs::Boolean(input.parse::<LitBool>()?.value));
+            }
+
+            WhenArgs::cfg(input).map(WhenArgs::Cfg)
+        })
+        .parse2(args)
+    }
+
+    fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        let holds = match args {
+            WhenArgs::Boolean(value) => *value,
+            WhenArgs::Cfg(args) => args.holds()?,
+        };
+
+        Ok(if holds { input } else { TokenStream2::new() })
+    }
+}
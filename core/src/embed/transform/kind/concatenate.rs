@@ -3,11 +3,15 @@ use std::str::FromStr as _;
 use phf::{phf_map, Map};
 use proc_macro2::{TokenStream as TokenStream2, TokenTree as TokenTree2};
 use quote::ToTokens;
-use syn::{spanned::Spanned, Error, Ident, Lit};
+use syn::{ext::IdentExt as _, parse::Parser as _, Error, Ident, Lit};
 
 use crate::embed::transform::Transformate;
 
-use super::TransformFlatten;
+use super::{
+    span::{joined, respan},
+    flatten::FlattenArgs,
+    TransformFlatten,
+};
 
 static RECOGNIZED_MODES: Map<&str, Concatenate> = phf_map! {
     "ident" => Concatenate::Ident,
@@ -68,9 +72,9 @@ impl Transformate for TransformConcatenate {
     fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
         match args {
             Concatenate::Ident | Concatenate::RawIdent => {
-                let span = input.span();
+                let span = joined(&input);
 
-                let input = <TransformFlatten as Transformate>::apply(input, &())?;
+                let input = <TransformFlatten as Transformate>::apply(input, &FlattenArgs::default())?;
 
                 let mut target_ident = String::new();
 
@@ -83,10 +87,10 @@ impl Transformate for TransformConcatenate {
                                 Lit::Char(target_char) => target_ident.push(target_char.value()),
                                 Lit::Int(target_int) => target_ident
                                     // NOTE: This gets rid of the literal suffix.
-                                    .push_str(&target_int.base10_digits()),
+                                    .push_str(target_int.base10_digits()),
                                 Lit::Float(target_float) => target_ident
                                     // NOTE: This gets rid of the literal suffix.
-                                    .push_str(&target_float.base10_digits()),
+                                    .push_str(target_float.base10_digits()),
                                 Lit::Bool(target_bool) => {
                                     target_ident.push_str(&target_bool.value.to_string())
                                 }
@@ -100,20 +104,29 @@ impl Transformate for TransformConcatenate {
                                 _ => unimplemented!(),
                             }
                         }
-                        TokenTree2::Ident(ident) => target_ident.push_str(&ident.to_string()),
+                        // NOTE: A raw identifier is concatenated without its `r#`, which is only valid at the start of an identifier.
+                        TokenTree2::Ident(ident) => target_ident.push_str(&ident.unraw().to_string()),
                         TokenTree2::Group(_) => unreachable!("found group in flattened stream"),
                     }
                 }
 
-                let target_ident = if *args == Concatenate::RawIdent {
-                    Ident::new(format!("r#{}", target_ident).as_str(), span)
+                let target_repr = if *args == Concatenate::RawIdent {
+                    format!("r#{target_ident}")
                 } else {
-                    Ident::new(target_ident.as_str(), span)
+                    target_ident
                 };
 
+                let mut target_ident = Ident::parse_any.parse_str(&target_repr).map_err(|_| {
+                    Error::new(span, format!("`{target_repr}` is not a valid identifier"))
+                })?;
+
+                target_ident.set_span(span);
+
                 Ok(target_ident.into_token_stream())
             }
             Concatenate::String => {
+                let span = joined(&input);
+
                 let output =
                     input
                         .into_iter()
@@ -124,7 +137,7 @@ impl Transformate for TransformConcatenate {
                                 TokenTree2::Literal(lit) => {
                                     match syn::parse2::<syn::Lit>(lit.into_token_stream())? {
                                         syn::Lit::Str(target_str) => target_str.value(),
-                                        lit @ _ => lit.into_token_stream().to_string(),
+                                        lit => lit.into_token_stream().to_string(),
                                     }
                                 }
                                 _ => target_tree.to_string(),
@@ -142,6 +155,7 @@ impl Transformate for TransformConcatenate {
                 }
 
                 TokenStream2::from_str(&format!("r{separator}\"{output}\"{separator}"))
+                    .map(|output| respan(output, span))
                     .map_err(Into::into)
             }
         }
@@ -4,6 +4,8 @@ use proc_macro2::TokenStream as TokenStream2;
 
 use crate::embed::transform::Transformate;
 
+use super::span::{joined, respan};
+
 /// A transformation that counts the amount of [`proc_macro2::TokenTree`] contained in a [`TokenStream2`].
 ///
 /// # Example
@@ -25,8 +27,12 @@ impl Transformate for TransformCount {
     }
 
     fn apply(input: TokenStream2, _: &Self::Args) -> Result<TokenStream2, syn::Error> {
+        let span = joined(&input);
+
         let count = input.into_iter().count();
 
-        TokenStream2::from_str(count.to_string().as_str()).map_err(Into::into)
+        TokenStream2::from_str(count.to_string().as_str())
+            .map(|output| respan(output, span))
+            .map_err(Into::into)
     }
 }
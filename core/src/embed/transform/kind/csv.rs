@@ -0,0 +1,283 @@
+use std::{path::PathBuf, str::FromStr as _};
+
+use phf::{phf_map, Map};
+use proc_macro2::{Delimiter, Group, Literal, Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use syn::{
+    parse::{ParseStream, Parser as _},
+    Error, LitStr, Token,
+};
+
+use crate::embed::transform::Transformate;
+
+use super::{
+    include::{read, resolve},
+    literal::negative,
+};
+
+static RECOGNIZED_COLUMNS: Map<&str, Column> = phf_map! {
+    "string" => Column::String,
+    "ident" => Column::Ident,
+    "literal" => Column::Literal,
+};
+
+/// A transformation that appends the rows of a CSV file to the target [`TokenStream2`].
+///
+/// The path is resolved relative to the directory of the invoking crate's manifest, and tracked like [`TransformInclude`](super::include::TransformInclude).
+///
+/// Every row turns into a `(..)` group, holding a token per cell. Following the path, `header` skips the first row,
+/// and a list of [`Column`]s selects how the cells of each column are turned into tokens, unlisted columns are string literals.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// // data/units.csv:
+/// // name,symbol
+/// // Metre,m
+/// // Second,s
+/// embed! {
+///     for #row in [< :csv{"data/units.csv", header, ident string} >] {
+///         // `#row` is `(Metre "m")`, then `(Second "s")`.
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformCsv;
+
+/// The selected interpretation of the cells of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    /// A string literal, this is the default.
+    String,
+    /// An identifier.
+    Ident,
+    /// A literal, such as a number, as written.
+    Literal,
+}
+
+/// The parsed arguments of a [`TransformCsv`].
+#[derive(Debug, Clone)]
+pub struct CsvArgs {
+    /// The resolved path of the file.
+    pub path: PathBuf,
+    /// The span of the path, as written.
+    span: Span,
+    /// Whether to skip the first row.
+    has_header: bool,
+    /// The interpretation of each column, in order.
+    column_list: Vec<Column>,
+}
+
+impl Transformate for TransformCsv {
+    type Args = CsvArgs;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        (|input: ParseStream| {
+            let target_path = input.parse::<LitStr>()?;
+
+            let (mut has_header, mut column_list) = (false, Vec::new());
+
+            while input.parse::<Option<Token![,]>>()?.is_some() {
+                if input.peek(syn::Ident) && input.fork().parse::<syn::Ident>()? == "header" {
+                    input.parse::<syn::Ident>()?;
+
+                    has_header = true;
+
+                    continue;
+                }
+
+                while !input.is_empty() && !input.peek(Token![,]) {
+                    let target_ident = input.parse::<syn::Ident>()?;
+
+                    let target_repr = target_ident.to_string();
+
+                    column_list.push(
+                        RECOGNIZED_COLUMNS
+                            .get(target_repr.as_str())
+                            .copied()
+                            .ok_or_else(|| {
+                                Error::new(
+                                    target_ident.span(),
+                                    format!(
+                                "unknown column: `{target_repr}`, valid columns are: {columns}",
+                                columns = RECOGNIZED_COLUMNS
+                                    .keys()
+                                    .copied()
+                                    .collect::<Vec<&str>>()
+                                    .join(" ")
+                            ),
+                                )
+                            })?,
+                    );
+                }
+            }
+
+            Ok(CsvArgs {
+                path: resolve(&target_path)?,
+                span: target_path.span(),
+                has_header,
+                column_list,
+            })
+        })
+        .parse2(args)
+    }
+
+    fn apply(mut input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        let contents = read(&args.path, args.span)?;
+
+        let row_list = rows(&contents).map_err(|line| {
+            Error::new(
+                args.span,
+                format!(
+                    "failed to parse `{}` as CSV: unterminated quote on line {line}",
+                    args.path.display()
+                ),
+            )
+        })?;
+
+        for (position, row) in row_list
+            .into_iter()
+            .enumerate()
+            .skip(usize::from(args.has_header))
+        {
+            let cell_list = row
+                .iter()
+                .enumerate()
+                .map(|(column, cell)| {
+                    cell_to_tokens(
+                        cell,
+                        args.column_list
+                            .get(column)
+                            .copied()
+                            .unwrap_or(Column::String),
+                        args.span,
+                    )
+                    .map_err(|message| {
+                        Error::new(
+                            args.span,
+                            format!(
+                                "{message}, in row {row} column {column}",
+                                row = position + 1,
+                                column = column + 1
+                            ),
+                        )
+                    })
+                })
+                .collect::<Result<TokenStream2, Error>>()?;
+
+            let mut target_group = Group::new(Delimiter::Parenthesis, cell_list);
+
+            target_group.set_span(args.span);
+
+            input.extend(TokenStream2::from(TokenTree2::Group(target_group)));
+        }
+
+        Ok(input)
+    }
+}
+
+/// Turn a single cell into a token tree, following the interpretation of its column.
+fn cell_to_tokens(cell: &str, column: Column, span: Span) -> Result<TokenTree2, String> {
+    let literal = |mut target_literal: Literal| {
+        target_literal.set_span(span);
+
+        TokenTree2::Literal(target_literal)
+    };
+
+    match column {
+        Column::String => Ok(literal(Literal::string(cell))),
+        Column::Ident => syn::parse_str::<syn::Ident>(cell.trim())
+            .map(|mut target_ident| {
+                target_ident.set_span(span);
+
+                TokenTree2::Ident(target_ident)
+            })
+            .map_err(|_| format!("expected an identifier, found `{cell}`")),
+        Column::Literal => {
+            let (target_sign, target_repr) = match cell.trim().strip_prefix('-') {
+                Some(target_repr) => (true, target_repr),
+                None => (false, cell.trim()),
+            };
+
+            let target_literal = match TokenStream2::from_str(target_repr)
+                .map(|stream| stream.into_iter().collect::<Vec<_>>())
+                .as_deref()
+            {
+                Ok([TokenTree2::Literal(target_literal)]) => literal(target_literal.clone()),
+                _ => return Err(format!("expected a literal, found `{cell}`")),
+            };
+
+            Ok(if target_sign {
+                negative(target_literal, span)
+            } else {
+                target_literal
+            })
+        }
+    }
+}
+
+/// Split the contents into rows of cells, following RFC 4180.
+///
+/// Empty lines are skipped, unlike a line holding a single empty quoted cell, i.e. `""`.
+/// An unterminated quote is reported with the line it starts on.
+fn rows(contents: &str) -> Result<Vec<Vec<String>>, usize> {
+    let mut row_list = Vec::new();
+
+    let (mut row, mut cell) = (Vec::new(), String::new());
+
+    let (mut is_quoted, mut quote_line, mut line) = (false, 0, 1);
+
+    // NOTE: Whether the current row holds a quoted cell, as an empty one leaves no other trace.
+    let mut has_quote = false;
+
+    let mut iter = contents.chars().peekable();
+
+    while let Some(target_char) = iter.next() {
+        match (is_quoted, target_char) {
+            (true, '"') if iter.peek() == Some(&'"') => {
+                iter.next();
+
+                cell.push('"');
+            }
+            (true, '"') => is_quoted = false,
+            (false, '"') if cell.is_empty() => {
+                is_quoted = true;
+                has_quote = true;
+
+                quote_line = line;
+            }
+            (false, ',') => row.push(std::mem::take(&mut cell)),
+            (false, '\r') if iter.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                line += 1;
+
+                if !row.is_empty() || !cell.is_empty() || has_quote {
+                    row.push(std::mem::take(&mut cell));
+
+                    row_list.push(std::mem::take(&mut row));
+                }
+
+                has_quote = false;
+            }
+            (_, target_char) => {
+                if target_char == '\n' {
+                    line += 1;
+                }
+
+                cell.push(target_char);
+            }
+        }
+    }
+
+    if is_quoted {
+        return Err(quote_line);
+    }
+
+    if !row.is_empty() || !cell.is_empty() || has_quote {
+        row.push(cell);
+
+        row_list.push(row);
+    }
+
+    Ok(row_list)
+}
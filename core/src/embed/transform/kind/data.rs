@@ -0,0 +1,189 @@
+use std::path::PathBuf;
+
+use proc_macro2::{Delimiter, Group, Ident, Literal, Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use quote::quote_spanned;
+use syn::{parse::ParseStream, Error, LitStr, Token};
+
+use super::{include::resolve, literal::negative};
+
+/// A structured value read from a data source, independent of its format.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Data {
+    /// A missing value, e.g. `null` in JSON.
+    #[cfg_attr(not(feature = "json"), allow(dead_code))]
+    Null,
+    Boolean(bool),
+    Integer(i128),
+    Float(f64),
+    String(String),
+    Array(Vec<Data>),
+    /// The entries of a table, in the order they are written.
+    Table(Vec<(String, Data)>),
+}
+
+/// The parsed arguments of a data source transformation, that is a path optionally followed by a query.
+#[derive(Debug, Clone)]
+pub struct SourceArgs {
+    /// The resolved path of the file.
+    pub path: PathBuf,
+    /// The span of the path, as written.
+    pub span: Span,
+    /// The query selecting the value, empty for the whole document.
+    pub query: String,
+}
+
+impl SourceArgs {
+    /// Parse a path, relative to the invoking crate's manifest, optionally followed by a query.
+    pub fn parse(input: ParseStream) -> Result<Self, Error> {
+        let target_path = input.parse::<LitStr>()?;
+
+        Ok(Self {
+            path: resolve(&target_path)?,
+            span: target_path.span(),
+            query: Self::query(input)?,
+        })
+    }
+
+    /// Parse an optional query, preceded by a comma.
+    pub fn query(input: ParseStream) -> Result<String, Error> {
+        Ok(if input.parse::<Option<Token![,]>>()?.is_some() {
+            input.parse::<LitStr>()?.value()
+        } else {
+            String::new()
+        })
+    }
+}
+
+impl Data {
+    /// Select a value by a query of dot-separated keys and `[index]` accessors, e.g. `errors.codes[0]`.
+    ///
+    /// The whole document is selected if the query is empty.
+    pub fn select(&self, query: &str) -> Option<&Self> {
+        query
+            .split('.')
+            .filter(|segment| !segment.is_empty())
+            .try_fold(self, |value, segment| {
+                let (key, index_list) = segment.split_once('[').unwrap_or((segment, ""));
+
+                let value = match key {
+                    "" => value,
+                    key => match value {
+                        Self::Table(entry_list) => entry_list
+                            .iter()
+                            .find_map(|(name, value)| (name == key).then_some(value))?,
+                        Self::Array(element_list) => element_list.get(key.parse::<usize>().ok()?)?,
+                        _ => return None,
+                    },
+                };
+
+                index_list
+                    .split('[')
+                    .filter(|index| !index.is_empty())
+                    .try_fold(value, |value, index| match value {
+                        Self::Array(element_list) => element_list.get(index.strip_suffix(']')?.parse::<usize>().ok()?),
+                        _ => None,
+                    })
+            })
+    }
+
+    /// Select a value of the document, reporting a mismatched query at the span of the source.
+    pub fn select_from(&self, args: &SourceArgs) -> Result<&Self, Error> {
+        self.select(&args.query).ok_or_else(|| {
+            Error::new(
+                args.span,
+                format!(
+                    "query `{}` does not match any value in `{}`",
+                    args.query,
+                    args.path.display()
+                ),
+            )
+        })
+    }
+
+    /// Convert the value into a list of tokens.
+    ///
+    /// - `null` turns into nothing.
+    /// - Booleans turn into `true` or `false`.
+    /// - Numbers turn into unsuffixed literals, negative numbers are kept as a single token tree.
+    ///   Infinities and `NaN` turn into the constants of `f64`, e.g. `::core::primitive::f64::INFINITY`.
+    /// - Strings turn into string literals.
+    /// - Arrays turn into a list of their elements, and tables into a list of `(key value)` groups.
+    ///   Nested arrays and tables are wrapped into `[..]` and `{..}` groups respectively.
+    pub fn to_list(&self, span: Span) -> TokenStream2 {
+        match self {
+            Self::Array(element_list) => element_list.iter().map(|element| element.tokenize(span)).collect(),
+            Self::Table(entry_list) => entries(entry_list, span),
+            scalar => scalar.tokenize(span),
+        }
+    }
+
+    /// Convert the value into a single token tree, wrapping arrays and tables into groups.
+    fn tokenize(&self, span: Span) -> TokenStream2 {
+        let literal = |mut target_literal: Literal| {
+            target_literal.set_span(span);
+
+            TokenTree2::Literal(target_literal)
+        };
+
+        let target_tree = match self {
+            Self::Null => return TokenStream2::new(),
+            Self::Boolean(value) => TokenTree2::Ident(Ident::new(if *value { "true" } else { "false" }, span)),
+            Self::Integer(value) if *value < 0 => negative(literal(Literal::u128_unsuffixed(value.unsigned_abs())), span),
+            Self::Integer(value) => literal(Literal::u128_unsuffixed(value.unsigned_abs())),
+            // NOTE: Non-finite numbers have no literal, e.g. `inf` and `nan` in TOML, thus these turn into the associated constants.
+            Self::Float(value) if !value.is_finite() => {
+                let target_name = match value {
+                    value if value.is_nan() => "NAN",
+                    value if value.is_sign_negative() => "NEG_INFINITY",
+                    _ => "INFINITY",
+                };
+
+                let target_ident = Ident::new(target_name, span);
+
+                group(
+                    Delimiter::None,
+                    quote_spanned!(span=> ::core::primitive::f64::#target_ident),
+                    span,
+                )
+            }
+            Self::Float(value) if value.is_sign_negative() => negative(literal(Literal::f64_unsuffixed(value.abs())), span),
+            Self::Float(value) => literal(Literal::f64_unsuffixed(*value)),
+            Self::String(value) => literal(Literal::string(value)),
+            Self::Array(element_list) => group(
+                Delimiter::Bracket,
+                element_list.iter().map(|element| element.tokenize(span)).collect(),
+                span,
+            ),
+            Self::Table(entry_list) => group(Delimiter::Brace, entries(entry_list, span), span),
+        };
+
+        TokenStream2::from(target_tree)
+    }
+}
+
+/// Convert the entries of a table into a list of `(key value)` groups.
+fn entries(entry_list: &[(String, Data)], span: Span) -> TokenStream2 {
+    entry_list
+        .iter()
+        .map(|(key, value)| {
+            let mut target_key = Literal::string(key);
+
+            target_key.set_span(span);
+
+            let mut entry = TokenStream2::from(TokenTree2::Literal(target_key));
+
+            entry.extend(value.tokenize(span));
+
+            group(Delimiter::Parenthesis, entry, span)
+        })
+        .collect()
+}
+
+/// Create a group with the given span.
+fn group(delimiter: Delimiter, stream: TokenStream2, span: Span) -> TokenTree2 {
+    let mut target_group = Group::new(delimiter, stream);
+
+    target_group.set_span(span);
+
+    TokenTree2::Group(target_group)
+}
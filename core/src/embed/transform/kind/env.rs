@@ -0,0 +1,101 @@
+use std::env;
+
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
+use quote::ToTokens as _;
+use syn::{
+    parse::{ParseStream, Parser as _},
+    Error, Ident, LitStr, Token,
+};
+
+use crate::embed::transform::Transformate;
+
+/// A transformation that appends the value of an environment variable, as seen at compile time, to the target [`TokenStream2`].
+///
+/// The name is given either as an identifier or a string literal, optionally followed by a default string literal used when the variable is not set.
+/// The variable is tracked like a file, see [`TransformInclude`](super::include::TransformInclude).
+///
+/// The value is appended as a string literal, see `unstringify` or `concatenate` to fold it into identifiers.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     const NAME: &str = [< :env{CARGO_PKG_NAME} >]; // Expands to the name of the invoking crate.
+///
+///     const PREFIX: &str = [< :env{"MYCRATE_PREFIX", "my"} >]; // Expands to `"my"`, unless `MYCRATE_PREFIX` is set.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformEnv;
+
+/// The parsed arguments of a [`TransformEnv`].
+#[derive(Debug, Clone)]
+pub struct EnvArgs {
+    /// The name of the variable.
+    pub name: String,
+    /// The span of the name, as written.
+    span: Span,
+    /// The value used when the variable is not set.
+    default: Option<String>,
+}
+
+impl Transformate for TransformEnv {
+    type Args = EnvArgs;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        (|input: ParseStream| {
+            let (name, span) = if input.peek(LitStr) {
+                let target_name = input.parse::<LitStr>()?;
+
+                (target_name.value(), target_name.span())
+            } else {
+                let target_name = input.parse::<Ident>()?;
+
+                (target_name.to_string(), target_name.span())
+            };
+
+            let default = if input.parse::<Option<Token![,]>>()?.is_some() {
+                Some(input.parse::<LitStr>()?.value())
+            } else {
+                None
+            };
+
+            Ok(EnvArgs {
+                name,
+                span,
+                default,
+            })
+        })
+        .parse2(args)
+    }
+
+    fn apply(mut input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        let value = match env::var(&args.name) {
+            Ok(value) => value,
+            Err(env::VarError::NotPresent) => args.default.clone().ok_or_else(|| {
+                Error::new(
+                    args.span,
+                    format!(
+                        "environment variable `{}` is not set, consider adding a default: `env{{{}, \"..\"}}`",
+                        args.name, args.name
+                    ),
+                )
+            })?,
+            Err(env::VarError::NotUnicode(_)) => {
+                return Err(Error::new(
+                    args.span,
+                    format!("environment variable `{}` is not valid unicode", args.name),
+                ))
+            }
+        };
+
+        let mut target_literal = Literal::string(&value);
+
+        target_literal.set_span(args.span);
+
+        input.extend(target_literal.into_token_stream());
+
+        Ok(input)
+    }
+}
@@ -0,0 +1,116 @@
+use proc_macro2::Group as Group2;
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::TokenTree as TokenTree2;
+use quote::ToTokens as _;
+use syn::{
+    parse::{ParseStream, Parser as _},
+    Error, Ident, LitInt, Token,
+};
+
+use crate::embed::transform::Transformate;
+
+use super::ungroup::Delimiters;
+
+/// A transformation that flattens the target [`TokenStream2`].
+///
+/// Optionally, only the groups of the given delimiters are flattened, while the others are kept along with their flattened contents,
+/// and `depth = n` limits the flattening to the outermost `n` levels of groups.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use tokel::embed;
+/// embed! {
+///     [< (hello [world]):flatten >]; // Expands to `hello world`.
+///
+///     [< (hello ((world))):flatten{depth = 2} >]; // Expands to `hello (world)`.
+///
+///     [< (hello [world]):flatten{bracket} >]; // Expands to `(hello world)`.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformFlatten;
+
+/// The parsed arguments of a [`TransformFlatten`].
+#[derive(Debug, Clone, Default)]
+pub struct FlattenArgs {
+    /// The delimiters of the groups to flatten.
+    delimiters: Delimiters,
+    /// The number of levels to flatten, unlimited if not given.
+    depth: Option<usize>,
+}
+
+impl Transformate for TransformFlatten {
+    type Args = FlattenArgs;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, syn::Error> {
+        (|input: ParseStream| {
+            let mut target_args = FlattenArgs::default();
+
+            while !input.is_empty() {
+                // NOTE: Every option consumes at least one token or fails, so that malformed arguments cannot stall the loop.
+                if !input.peek(Ident) {
+                    return Err(input.error("expected a delimiter or `depth = n`"));
+                }
+
+                if !input.peek2(Token![=]) {
+                    target_args.delimiters.push(input)?;
+                } else {
+                    let target_ident = input.parse::<Ident>()?;
+
+                    if target_ident != "depth" {
+                        return Err(Error::new(
+                            target_ident.span(),
+                            format!("unknown option: `{target_ident}`, the only option is `depth`"),
+                        ));
+                    }
+
+                    input.parse::<Token![=]>()?;
+
+                    target_args.depth = Some(input.parse::<LitInt>()?.base10_parse()?);
+                }
+
+                input.parse::<Option<Token![,]>>()?;
+            }
+
+            Ok(target_args)
+        })
+        .parse2(args)
+    }
+
+    fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, syn::Error> {
+        if args.depth == Some(0) {
+            return Ok(input);
+        }
+
+        let inner_args = FlattenArgs {
+            delimiters: args.delimiters.clone(),
+            depth: args.depth.map(|depth| depth - 1),
+        };
+
+        input
+            .into_iter()
+            .try_fold(TokenStream2::new(), |mut acc, target_tree| {
+                let target_output = match target_tree {
+                    TokenTree2::Group(group) if args.delimiters.contains(group.delimiter()) => {
+                        <Self as Transformate>::apply(group.stream(), &inner_args)?
+                    }
+                    TokenTree2::Group(group) => {
+                        let mut target_group = Group2::new(
+                            group.delimiter(),
+                            <Self as Transformate>::apply(group.stream(), &inner_args)?,
+                        );
+
+                        target_group.set_span(group.span());
+
+                        TokenTree2::Group(target_group).into_token_stream()
+                    }
+                    _ => target_tree.into_token_stream(),
+                };
+
+                target_output.to_tokens(&mut acc);
+
+                Ok(acc)
+            })
+    }
+}
@@ -0,0 +1,117 @@
+use std::{env, path::Path};
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::ToTokens;
+use syn::{ext::IdentExt as _, parse::Parser as _, Error, Ident};
+
+use crate::embed::transform::Transformate;
+
+use super::{
+    concatenate::{Concatenate, TransformConcatenate},
+    flatten::FlattenArgs,
+    TransformFlatten,
+};
+
+/// The offset basis of the 64-bit FNV-1a hash.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The prime of the 64-bit FNV-1a hash.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A transformation that turns the target [`TokenStream2`] into a unique identifier.
+///
+/// The target stream is concatenated into an identifier, which is then prefixed with `__permafrost_` and suffixed
+/// with a hash of the invocation site, the text of every token and the location of every token that stems from
+/// outside of the invocation, such as the input of a `macro_rules!` wrapper.
+///
+/// As the hash only depends on the source, the generated identifier is stable across builds and the same within
+/// a single invocation, yet differs between expansions of a `macro_rules!` wrapper as long as one of the tokens stems from its input.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// macro_rules! checked {
+///     ($name:ident) => {
+///         embed! {
+///             const [< checks $name >]:gensym : &str = stringify!($name); // Expands to `const __permafrost_checksfoo_5c1f0e2a`.
+///         }
+///     };
+/// }
+///
+/// checked!(foo);
+/// checked!(foo); // Does not conflict with the constant above.
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformGensym;
+
+impl Transformate for TransformGensym {
+    type Args = ();
+
+    fn new(_: TokenStream2) -> Result<Self::Args, Error> {
+        Ok(())
+    }
+
+    fn apply(input: TokenStream2, _: &Self::Args) -> Result<TokenStream2, Error> {
+        let call_site = Span::call_site();
+
+        let hash = <TransformFlatten as Transformate>::apply(input.clone(), &FlattenArgs::default())?
+            .into_iter()
+            .fold(fnv(FNV_OFFSET_BASIS, &location(call_site)), |hash, target_tree| {
+                let span = target_tree.span();
+
+                let hash = fnv(hash, target_tree.to_string().as_bytes());
+
+                // NOTE: Tokens written inside of the invocation are located the same way on every expansion.
+                if span.file() == call_site.file() && span.start() >= call_site.start() && span.end() <= call_site.end() {
+                    hash
+                } else {
+                    fnv(hash, &location(span))
+                }
+            });
+
+        let target_ident = Ident::parse_any.parse2(TransformConcatenate::apply(input, &Concatenate::Ident)?)?;
+
+        // NOTE: Folding the hash keeps identifiers short, while still leaving 32 bits to avoid collisions.
+        let folded = (hash ^ (hash >> 32)) as u32;
+
+        Ok(Ident::new(
+            &format!("__permafrost_{target_ident}_{folded:08x}"),
+            target_ident.span(),
+        )
+        .into_token_stream())
+    }
+}
+
+/// Feed the given bytes into a 64-bit FNV-1a hash.
+fn fnv(hash: u64, bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(hash, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME))
+}
+
+/// Encode the location of the given span, to be fed into a hash.
+///
+/// The file is made relative to the directory of the invoking crate's manifest, as it may be absolute,
+/// which would tie the hash to the location of the checkout.
+fn location(span: Span) -> Vec<u8> {
+    let start = span.start();
+
+    let file = span.file();
+
+    let file = env::var_os("CARGO_MANIFEST_DIR")
+        .and_then(|manifest_dir| {
+            Path::new(&file)
+                .strip_prefix(manifest_dir)
+                .ok()
+                .map(|relative| relative.to_string_lossy().into_owned())
+        })
+        .unwrap_or(file);
+
+    [
+        file.as_bytes(),
+        &start.line.to_le_bytes(),
+        &start.column.to_le_bytes(),
+    ]
+    .concat()
+}
@@ -0,0 +1,107 @@
+use phf::{phf_map, Map};
+use proc_macro2::{Group as Group2, Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use syn::{Error, Ident};
+
+use crate::embed::transform::Transformate;
+
+static RECOGNIZED_MODES: Map<&str, Hygiene> = phf_map! {
+    "call_site" => Hygiene::CallSite,
+    "mixed_site" => Hygiene::MixedSite,
+    // NOTE: `Span::def_site` is unstable, `Span::mixed_site` is the closest stable resolution.
+    "def_site" => Hygiene::MixedSite,
+};
+
+/// A transformation that changes the name resolution of every identifier in the target [`TokenStream2`].
+///
+/// Only the resolution is changed, identifiers keep pointing at their original location.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// macro_rules! offset {
+///     ($name:ident, $value:expr) => {
+///         embed! {
+///             {
+///                 let [< $name _offset >]:concatenate:hygiene{mixed_site} = 1;
+///
+///                 $value + [< $name _offset >]:concatenate:hygiene{mixed_site}
+///             }
+///         }
+///     };
+/// }
+///
+/// let x_offset = 10;
+///
+/// assert_eq!(offset!(x, x_offset), 11); // The caller's `x_offset` is not shadowed.
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformHygiene;
+
+/// The selected name resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hygiene {
+    /// Resolve as if written at the call site, this is unhygienic.
+    CallSite,
+
+    /// Resolve local variables, labels and `$crate` at the definition site, the same as `macro_rules!`.
+    MixedSite,
+}
+
+impl Transformate for TransformHygiene {
+    type Args = Hygiene;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        let target_ident: Ident = syn::parse2(args)?;
+
+        let target_repr = target_ident.to_string();
+
+        RECOGNIZED_MODES
+            .get(target_repr.as_str())
+            .copied()
+            .ok_or_else(|| {
+                Error::new(
+                    target_ident.span(),
+                    format!(
+                        "unknown hygiene: `{target_repr}`, valid hygienes are: {modes}",
+                        modes = RECOGNIZED_MODES
+                            .keys()
+                            .copied()
+                            .collect::<Vec<&str>>()
+                            .join(" ")
+                    ),
+                )
+            })
+    }
+
+    fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        let target_span = match args {
+            Hygiene::CallSite => Span::call_site(),
+            Hygiene::MixedSite => Span::mixed_site(),
+        };
+
+        Ok(resolve_at(input, target_span))
+    }
+}
+
+/// Recursively resolve every identifier in the target [`TokenStream2`] at the given span.
+fn resolve_at(input: TokenStream2, span: Span) -> TokenStream2 {
+    input
+        .into_iter()
+        .map(|target_tree| match target_tree {
+            TokenTree2::Ident(mut ident) => {
+                ident.set_span(ident.span().resolved_at(span));
+
+                TokenTree2::Ident(ident)
+            }
+            TokenTree2::Group(group) => {
+                let mut new_group = Group2::new(group.delimiter(), resolve_at(group.stream(), span));
+
+                new_group.set_span(group.span());
+
+                TokenTree2::Group(new_group)
+            }
+            target_tree => target_tree,
+        })
+        .collect()
+}
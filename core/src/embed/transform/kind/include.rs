@@ -0,0 +1,149 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    str::FromStr as _,
+};
+
+use phf::{phf_map, Map};
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
+use quote::ToTokens as _;
+use syn::{
+    parse::{ParseStream, Parser as _},
+    Error, Ident, LitStr, Token,
+};
+
+use crate::embed::transform::Transformate;
+
+use super::span::respan;
+
+static RECOGNIZED_MODES: Map<&str, Include> = phf_map! {
+    "string" => Include::String,
+    "tokens" => Include::Tokens,
+};
+
+/// A transformation that appends the contents of a file to the target [`TokenStream2`].
+///
+/// The path is resolved relative to the directory of the invoking crate's manifest.
+/// The file is tracked as a dependency of the invocation, thus the crate is rebuilt whenever it changes,
+/// see [`Context::finish`](crate::embed::context::Context::finish).
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     const NAMES: &str = [< :include{"data/names.txt"} >]; // Expands to the contents of `data/names.txt`.
+///
+///     const TABLE: &[u8] = &[< :include{"data/table.rs", tokens} >]; // Expands to the tokens within `data/table.rs`.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformInclude;
+
+/// The selected interpretation of the file contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Include {
+    /// Append the contents as a single string literal (`default mode`).
+    String,
+    /// Parse the contents as Rust tokens.
+    Tokens,
+}
+
+/// The parsed arguments of a [`TransformInclude`].
+#[derive(Debug, Clone)]
+pub struct IncludeArgs {
+    /// The resolved path of the file.
+    pub path: PathBuf,
+    /// The span of the path, as written.
+    span: Span,
+    /// How to interpret the file contents.
+    mode: Include,
+}
+
+impl Transformate for TransformInclude {
+    type Args = IncludeArgs;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        (|input: ParseStream| {
+            let target_path = input.parse::<LitStr>()?;
+
+            let mode = if input.parse::<Option<Token![,]>>()?.is_some() {
+                let target_ident = input.parse::<Ident>()?;
+
+                let target_repr = target_ident.to_string();
+
+                RECOGNIZED_MODES.get(target_repr.as_str()).copied().ok_or_else(|| {
+                    Error::new(
+                        target_ident.span(),
+                        format!(
+                            "unknown mode: `{target_repr}`, valid modes are: {modes}",
+                            modes = RECOGNIZED_MODES
+                                .keys()
+                                .copied()
+                                .collect::<Vec<&str>>()
+                                .join(" ")
+                        ),
+                    )
+                })?
+            } else {
+                Include::String
+            };
+
+            Ok(IncludeArgs {
+                path: resolve(&target_path)?,
+                span: target_path.span(),
+                mode,
+            })
+        })
+        .parse2(args)
+    }
+
+    fn apply(mut input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        let contents = read(&args.path, args.span)?;
+
+        match args.mode {
+            Include::String => {
+                let mut target_literal = Literal::string(&contents);
+
+                target_literal.set_span(args.span);
+
+                input.extend(target_literal.into_token_stream());
+            }
+            Include::Tokens => input.extend(respan(
+                TokenStream2::from_str(&contents).map_err(|err| {
+                    Error::new(
+                        args.span,
+                        format!("failed to parse `{}` as tokens: {err}", args.path.display()),
+                    )
+                })?,
+                args.span,
+            )),
+        }
+
+        Ok(input)
+    }
+}
+
+/// Resolve a path relative to the directory of the invoking crate's manifest.
+pub fn resolve(path: &LitStr) -> Result<PathBuf, Error> {
+    let target_path = PathBuf::from(path.value());
+
+    if target_path.is_absolute() {
+        return Ok(target_path);
+    }
+
+    env::var_os("CARGO_MANIFEST_DIR")
+        .map(|manifest_dir| Path::new(&manifest_dir).join(&target_path))
+        .ok_or_else(|| {
+            Error::new(
+                path.span(),
+                "cannot resolve a relative path, as `CARGO_MANIFEST_DIR` is not set",
+            )
+        })
+}
+
+/// Read the contents of a file, reporting any failure at the given span.
+pub fn read(path: &Path, span: Span) -> Result<String, Error> {
+    fs::read_to_string(path)
+        .map_err(|err| Error::new(span, format!("failed to read `{}`: {err}", path.display())))
+}
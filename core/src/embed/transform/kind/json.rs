@@ -0,0 +1,78 @@
+use proc_macro2::TokenStream as TokenStream2;
+use serde_json::Value;
+use syn::{parse::Parser as _, Error};
+
+use crate::embed::transform::Transformate;
+
+use super::{
+    data::{Data, SourceArgs},
+    include::read,
+};
+
+/// A transformation that appends a value of a JSON file to the target [`TokenStream2`].
+///
+/// The path is resolved relative to the directory of the invoking crate's manifest, and tracked like [`TransformInclude`](super::include::TransformInclude).
+///
+/// The value is selected with a query of dot-separated keys and `[index]` accessors, e.g. `"errors.codes[0]"`,
+/// the whole document is selected if no query is given. The value is then converted into tokens, see [`Data::to_list`].
+///
+/// Strings turn into string literals, see `unstringify` to turn these into identifiers.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// // data/opcodes.json: { "opcodes": ["Nop", "Push", "Pop"] }
+/// embed! {
+///     enum Opcode {
+///         for #opcode in [< :json{"data/opcodes.json", "opcodes"} >] {
+///             [< #opcode:unstringify >],
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformJson;
+
+impl Transformate for TransformJson {
+    type Args = SourceArgs;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        SourceArgs::parse.parse2(args)
+    }
+
+    fn apply(mut input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        let document = serde_json::from_str::<Value>(&read(&args.path, args.span)?).map_err(|err| {
+            Error::new(
+                args.span,
+                format!("failed to parse `{}` as JSON: {err}", args.path.display()),
+            )
+        })?;
+
+        input.extend(Data::from(document).select_from(args)?.to_list(args.span));
+
+        Ok(input)
+    }
+}
+
+impl From<Value> for Data {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(value) => Self::Boolean(value),
+            Value::Number(number) => number
+                .as_i64()
+                .map(i128::from)
+                .or_else(|| number.as_u64().map(i128::from))
+                .map_or_else(|| Self::Float(number.as_f64().unwrap_or_default()), Self::Integer),
+            Value::String(value) => Self::String(value),
+            Value::Array(element_list) => Self::Array(element_list.into_iter().map(Self::from).collect()),
+            Value::Object(entry_map) => Self::Table(
+                entry_map
+                    .into_iter()
+                    .map(|(key, value)| (key, Self::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
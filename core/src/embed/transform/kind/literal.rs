@@ -0,0 +1,19 @@
+use proc_macro2::{Delimiter, Group, Punct, Spacing, Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+
+/// Negate a literal, keeping it as a single token tree, so that it is a single element of a list.
+pub fn negative(target_literal: TokenTree2, span: Span) -> TokenTree2 {
+    let mut target_sign = Punct::new('-', Spacing::Alone);
+
+    target_sign.set_span(span);
+
+    let mut target_group = Group::new(
+        Delimiter::None,
+        [TokenTree2::Punct(target_sign), target_literal]
+            .into_iter()
+            .collect::<TokenStream2>(),
+    );
+
+    target_group.set_span(span);
+
+    TokenTree2::Group(target_group)
+}
@@ -0,0 +1,196 @@
+use proc_macro2::{Delimiter, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use syn::{
+    braced,
+    parse::{ParseStream, Parser as _},
+    token::Brace,
+    Error, Ident, Result, Token,
+};
+
+use crate::embed::transform::Fragment;
+
+use super::span::joined;
+
+/// A transformation that dispatches on the shape of the target [`TokenStream2`].
+///
+/// Arms are tried in order, the first arm whose pattern matches the whole target stream is selected.
+/// A pattern is a delimited group of tokens, that must match exactly, and captures of the form `#name:fragment`,
+/// see [`Fragment`] for the available fragments. The wildcard pattern `_` matches anything.
+///
+/// The output of the selected arm is then expanded within its own scope, with all captures bound.
+///
+/// Unlike other transformations, the arguments are not expanded beforehand, as arms may reference captures.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// macro_rules! describe {
+///     ($($tt:tt)*) => {
+///         embed! {
+///             const DESCRIPTION: &str = [< $($tt)* >]:match{
+///                 (#name:ident) => { [< "identifier " #name >]:concatenate{string} }
+///                 (#value:literal) => { [< "literal " #value:stringify >]:concatenate{string} }
+///                 _ => { "something else" }
+///             };
+///         }
+///     };
+/// }
+///
+/// describe!(foo); // Expands to `const DESCRIPTION: &str = "identifier foo";`.
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformMatch;
+
+/// A single arm of a [`TransformMatch`].
+#[derive(Debug, Clone)]
+pub struct Arm {
+    /// The pattern to match, or [`None`] for the wildcard pattern.
+    pattern: Option<Vec<Matcher>>,
+    /// The unexpanded output of the arm.
+    output: TokenStream2,
+}
+
+/// A single element of a pattern.
+#[derive(Debug, Clone)]
+enum Matcher {
+    /// Capture a fragment under the given name.
+    Capture(Ident, Fragment),
+    /// Match a group with the given delimiter, and its contents.
+    Group(Delimiter, Vec<Matcher>),
+    /// Match a single token exactly.
+    Token(TokenTree2),
+}
+
+/// The captures of a matched pattern, by name.
+pub type Captures = Vec<(Ident, TokenStream2)>;
+
+impl TransformMatch {
+    /// Parse the arms of the transformation.
+    pub fn arms(args: TokenStream2) -> Result<Vec<Arm>> {
+        (|input: ParseStream| {
+            let mut arm_list = Vec::new();
+
+            while !input.is_empty() {
+                let pattern = if input.peek(Token![_]) {
+                    input.parse::<Token![_]>()?;
+
+                    None
+                } else {
+                    match input.parse::<TokenTree2>()? {
+                        TokenTree2::Group(group) => Some(Matcher::list.parse2(group.stream())?),
+                        target_tree => {
+                            return Err(Error::new(
+                                target_tree.span(),
+                                "expected a delimited pattern, e.g. `(#name:ident)`, or `_`",
+                            ))
+                        }
+                    }
+                };
+
+                input.parse::<Token![=>]>()?;
+
+                let output = if input.peek(Brace) {
+                    let output;
+
+                    braced!(output in input);
+
+                    input.parse::<Option<Token![,]>>()?;
+
+                    output.parse::<TokenStream2>()?
+                } else {
+                    let mut output = TokenStream2::new();
+
+                    while !input.is_empty() && !input.peek(Token![,]) {
+                        output.extend(std::iter::once(input.parse::<TokenTree2>()?));
+                    }
+
+                    input.parse::<Option<Token![,]>>()?;
+
+                    output
+                };
+
+                arm_list.push(Arm { pattern, output });
+            }
+
+            Ok(arm_list)
+        })
+        .parse2(args)
+    }
+
+    /// Select the first arm matching the target stream, returning its captures and unexpanded output.
+    pub fn select(input: TokenStream2, arms: &[Arm]) -> Result<(Captures, TokenStream2)> {
+        arms.iter()
+            .find_map(|Arm { pattern, output }| match pattern {
+                None => Some((Captures::new(), output.clone())),
+                Some(pattern) => (|input: ParseStream| Matcher::apply(pattern, input))
+                    .parse2(input.clone())
+                    .ok()
+                    .map(|captures| (captures, output.clone())),
+            })
+            .ok_or_else(|| {
+                Error::new(
+                    joined(&input),
+                    format!("no arm matches `{input}`, consider adding a wildcard arm: `_ => {{ .. }}`"),
+                )
+            })
+    }
+}
+
+impl Matcher {
+    /// Parse a list of matchers, making up a pattern.
+    fn list(input: ParseStream) -> Result<Vec<Self>> {
+        let mut matcher_list = Vec::new();
+
+        while !input.is_empty() {
+            if input.peek(Token![#]) && input.peek2(Ident) {
+                input.parse::<Token![#]>()?;
+
+                let name = input.parse::<Ident>()?;
+
+                if !input.peek(Token![:]) {
+                    return Err(Error::new(
+                        name.span(),
+                        format!("expected a fragment for capture `#{name}`, e.g. `#{name}:ident`"),
+                    ));
+                }
+
+                input.parse::<Token![:]>()?;
+
+                matcher_list.push(Self::Capture(name, input.parse()?));
+
+                continue;
+            }
+
+            matcher_list.push(match input.parse::<TokenTree2>()? {
+                TokenTree2::Group(group) => Self::Group(group.delimiter(), Self::list.parse2(group.stream())?),
+                target_tree => Self::Token(target_tree),
+            });
+        }
+
+        Ok(matcher_list)
+    }
+
+    /// Match the input against a pattern, returning all captures.
+    fn apply(pattern: &[Self], input: ParseStream) -> Result<Captures> {
+        pattern.iter().try_fold(Captures::new(), |mut captures, matcher| {
+            match matcher {
+                Self::Capture(name, fragment) => captures.push((name.clone(), fragment.parse_from(input)?)),
+                Self::Group(delimiter, pattern) => match input.parse::<TokenTree2>()? {
+                    TokenTree2::Group(group) if group.delimiter() == *delimiter => {
+                        captures.extend((|input: ParseStream| Self::apply(pattern, input)).parse2(group.stream())?)
+                    }
+                    target_tree => return Err(Error::new(target_tree.span(), "mismatched group")),
+                },
+                Self::Token(target) => {
+                    let target_tree = input.parse::<TokenTree2>()?;
+
+                    if target_tree.to_string() != target.to_string() {
+                        return Err(Error::new(target_tree.span(), "mismatched token"));
+                    }
+                }
+            }
+
+            Ok(captures)
+        })
+    }
+}
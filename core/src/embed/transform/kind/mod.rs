@@ -0,0 +1,732 @@
+mod append;
+mod case;
+mod cfg;
+mod concatenate;
+mod csv;
+mod env;
+#[cfg(any(feature = "json", feature = "toml"))]
+mod data;
+mod flatten;
+mod gensym;
+mod hygiene;
+mod include;
+#[cfg(feature = "json")]
+mod json;
+mod literal;
+mod matching;
+mod parse;
+mod predicate;
+mod reflect;
+mod prefix;
+mod reverse;
+mod span;
+mod stringify;
+#[cfg(feature = "toml")]
+mod toml;
+mod ungroup;
+mod unstringify;
+mod count;
+
+pub use self::{
+    case::TransformCase, concatenate::TransformConcatenate, flatten::TransformFlatten,
+    reverse::TransformReverse, stringify::TransformStringify, ungroup::TransformUngroup,
+};
+
+use append::TransformAppend;
+use cfg::{TransformCfg, TransformWhen};
+use count::TransformCount;
+use csv::TransformCsv;
+use env::TransformEnv;
+use gensym::TransformGensym;
+use hygiene::TransformHygiene;
+use include::TransformInclude;
+#[cfg(feature = "json")]
+use json::TransformJson;
+use matching::TransformMatch;
+use parse::TransformParse;
+use predicate::{TransformContains, TransformIsEmpty, TransformIsIdent, TransformIsLiteral, TransformNot};
+use prefix::TransformPrefix;
+use reflect::{TransformAttrs, TransformFields, TransformGenerics, TransformName, TransformVariants};
+use span::TransformSpan;
+#[cfg(feature = "toml")]
+use toml::TransformToml;
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::TokenTree as TokenTree2;
+use quote::quote;
+use syn::Ident;
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream},
+    token::Brace,
+    Error, Token,
+};
+
+use phf::{phf_map, Map};
+use unstringify::TransformUnstringify;
+
+use crate::embed::{context::Context, Embed};
+
+use super::{Transformate, Transformer as _};
+
+static RECOGNIZED_TRANSFORMS: Map<&str, TransformKind> = phf_map! {
+    "case" => TransformKind::Case,
+    "ungroup" => TransformKind::Ungroup,
+    "flatten" => TransformKind::Flatten,
+    "reverse" => TransformKind::Reverse,
+    "stringify" => TransformKind::Stringify,
+    "unstringify" => TransformKind::Unstringify,
+    "concatenate" => TransformKind::Concatenate,
+    "append" => TransformKind::Append,
+    "prefix" => TransformKind::Prefix,
+    "count" => TransformKind::Count,
+    "span" => TransformKind::Span,
+    "hygiene" => TransformKind::Hygiene,
+    "gensym" => TransformKind::Gensym,
+    "is_empty" => TransformKind::IsEmpty,
+    "is_ident" => TransformKind::IsIdent,
+    "is_literal" => TransformKind::IsLiteral,
+    "contains" => TransformKind::Contains,
+    "not" => TransformKind::Not,
+    "match" => TransformKind::Match,
+    "include" => TransformKind::Include,
+    "json" => TransformKind::Json,
+    "toml" => TransformKind::Toml,
+    "csv" => TransformKind::Csv,
+    "env" => TransformKind::Env,
+    "feature" => TransformKind::Cfg,
+    "target_arch" => TransformKind::Cfg,
+    "target_os" => TransformKind::Cfg,
+    "target_family" => TransformKind::Cfg,
+    "target_env" => TransformKind::Cfg,
+    "target_vendor" => TransformKind::Cfg,
+    "target_endian" => TransformKind::Cfg,
+    "target_pointer_width" => TransformKind::Cfg,
+    "target_feature" => TransformKind::Cfg,
+    "when" => TransformKind::When,
+    "name" => TransformKind::Name,
+    "fields" => TransformKind::Fields,
+    "variants" => TransformKind::Variants,
+    "generics" => TransformKind::Generics,
+    "attrs" => TransformKind::Attrs,
+    "parse" => TransformKind::Parse,
+};
+
+/// A single transformation kind.
+///
+/// This encapsulates a single transformation, without its target token tree.
+#[derive(Debug, Clone)]
+#[repr(u8)]
+pub enum TransformKind {
+    /// Convert the token tree to a specific case.
+    ///
+    /// Using the [`Case`] enum, this will convert the token tree to the specified case.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use tokel::embed;
+    /// embed! {
+    ///    struct [< hello:case{pascal} >]; // Expands to `struct Hello;`.
+    /// }
+    /// ```
+    Case,
+    /// Flatten the [`TokenTree2`] if it is a [`TokenTree2::Group`], it is a no-op otherwise.
+    ///
+    /// This will remove all grouping tokens from the token tree, or only those of the given delimiters, e.g. `ungroup{none}`.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use tokel::embed;
+    /// embed! {
+    ///  [< (hello [world]):ungroup >]; // Expands to `hello world`.
+    /// }
+    Ungroup,
+
+    /// Recursively flatten the token tree.
+    ///
+    /// This will remove all grouping tokens from the token tree, or only those of the given delimiters,
+    /// up to the given depth, e.g. `flatten{none, depth = 2}`.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use tokel::embed;
+    /// embed! {
+    ///   [< (hello [world]):flatten >]; // Expands to `hello world`.
+    /// }
+    Flatten,
+
+    /// Reverse the [`TokenTree2`].
+    ///
+    /// Reverses the inner [`TokenStream2`] in case of [`TokenTree2::Group`], leaves it untouched otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use tokel::embed;
+    /// embed! {
+    ///  [< (hello [world]):reverse >]; // Expands to `[world] hello`.
+    /// }
+    Reverse,
+
+    /// Turn the [`TokenTree2`] into a string literal.
+    Stringify,
+
+    /// Turn any string literals in the [`TokenTree2`] into a [`TokenStream2`], including byte and C strings.
+    ///
+    /// Only the top-level literals are turned by default, `recursive` also turns the literals within groups.
+    ///
+    /// This is not necessarily the inverse of [`TransformKind::Stringify`], albeit it may be for some input-output pairs.
+    Unstringify,
+
+    /// Concatenate the [`TokenTree2`] into a single token, consicutively flatting any groups.
+    ///
+    /// [`TransformKind::Concatenate`] operates in two modes:
+    ///
+    /// - `Concatenate::Ident` will concatenate the tokens into a single identifier (`default mode`).
+    /// - `Concatenate::String`
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use tokel::embed;
+    /// embed! {
+    ///  [< (hello [world]):concatenate >]; // Expands to `helloworld`.
+    /// }
+    Concatenate,
+
+    /// Append a [`TokenStream2`] to the provided input.
+    Append,
+
+    /// Prefix a [`TokenStream2`] to the provided input.
+    Prefix,
+
+    /// Count the number of token trees in the [`TokenStream2`].
+    Count,
+
+    /// Re-span every token in the [`TokenStream2`].
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  [< (hello [world]):span{call_site} >]; // Expands to `(hello [world])`, resolved at the call site.
+    /// }
+    Span,
+
+    /// Change the name resolution of every identifier in the [`TokenStream2`].
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  let [< value >]:hygiene{mixed_site} = 1; // Expands to `let value = 1;`, invisible to the caller.
+    /// }
+    Hygiene,
+
+    /// Turn the [`TokenStream2`] into a unique, yet deterministic, identifier.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  static [< helpers >]:gensym : () = (); // Expands to `static __permafrost_helpers_0b4e21f7: () = ();`.
+    /// }
+    Gensym,
+
+    /// Check whether the [`TokenStream2`] is empty, expanding to `true` or `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  [< ():ungroup >]:is_empty; // Expands to `true`.
+    /// }
+    IsEmpty,
+
+    /// Check whether the [`TokenStream2`] is a single identifier, expanding to `true` or `false`.
+    IsIdent,
+
+    /// Check whether the [`TokenStream2`] is a single literal, expanding to `true` or `false`.
+    IsLiteral,
+
+    /// Check whether the [`TokenStream2`] contains the given token trees at any depth, expanding to `true` or `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  [< (hello [world]) >]:contains{world}; // Expands to `true`.
+    /// }
+    Contains,
+
+    /// Negate a predicate, turning `true` into `false` and vice versa.
+    Not,
+
+    /// Select the first arm whose pattern matches the [`TokenStream2`], and expand its output.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  [< 42 >]:match{ (#name:ident) => { #name }, (#value:literal) => { [< #value >]:stringify } }; // Expands to `"42"`.
+    /// }
+    Match,
+
+    /// Append the contents of a file, relative to the invoking crate's manifest, to the [`TokenStream2`].
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  const NAMES: &str = [< :include{"data/names.txt"} >]; // Expands to the contents of `data/names.txt`.
+    /// }
+    Include,
+
+    /// Append a value of a JSON file, relative to the invoking crate's manifest, to the [`TokenStream2`].
+    ///
+    /// Requires the `json` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  const CODES: &[u16] = &[for #code in [< :json{"data/errors.json", "codes"} >] { #code, }]; // Expands to `&[404, 500]`.
+    /// }
+    Json,
+
+    /// Append a value of a TOML file, relative to the invoking crate's manifest, to the [`TokenStream2`].
+    ///
+    /// Requires the `toml` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  const VERSION: u8 = [< :toml{metadata, "protocol.version"} >]; // Expands to `[package.metadata.protocol] version`.
+    /// }
+    Toml,
+
+    /// Append the rows of a CSV file, relative to the invoking crate's manifest, to the [`TokenStream2`] as `(..)` groups.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  enum Unit { for #unit in [< :csv{"data/units.csv", header, ident} >] { [< #unit:match{ ((#name:ident #symbol:literal)) => { #name } } >], } } // Expands to `enum Unit { Metre, Second }`.
+    /// }
+    Csv,
+
+    /// Append the value of an environment variable, as seen at compile time, to the [`TokenStream2`] as a string literal.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  const PREFIX: &str = [< :env{MYCRATE_PREFIX, "my"} >]; // Expands to `"my"`, unless `MYCRATE_PREFIX` is set.
+    /// }
+    Env,
+
+    /// Check whether a Cargo feature is enabled, or a `cfg` option, such as `target_arch`, has a given value.
+    ///
+    /// The predicate is named after the checked key, and follows the `CARGO_FEATURE_*` and `CARGO_CFG_*` environment of build scripts,
+    /// reporting an error if it is not forwarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  #if [< :feature{"serde"} >] { mod serde_impl; } // Expands to `mod serde_impl;` if `CARGO_FEATURE_SERDE` is set.
+    /// }
+    Cfg,
+
+    /// Keep the [`TokenStream2`] if the given condition holds, and drop it otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  struct [< Foo [< _serde >]:when{feature{"serde"}} >]:concatenate; // Expands to `struct Foo_serde;` if `serde` is enabled.
+    /// }
+    When,
+
+    /// Replace the item within the [`TokenStream2`] with its name.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// macro_rules! name {
+    ///     ($item:item) => { permafrost::embed! { [< $item:name >] } };
+    /// }
+    ///
+    /// name!(struct Point { x: f32, y: f32 }); // Expands to `Point`.
+    Name,
+
+    /// Replace the struct, union or function within the [`TokenStream2`] with a list of its fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// macro_rules! fields {
+    ///     ($item:item) => { permafrost::embed! { [< $item:fields >] } };
+    /// }
+    ///
+    /// fields!(struct Point { x: f32, y: f32 }); // Expands to `(x f32) (y f32)`.
+    Fields,
+
+    /// Replace the enum within the [`TokenStream2`] with a list of its variant names.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// macro_rules! variants {
+    ///     ($item:item) => { permafrost::embed! { [< $item:variants >] } };
+    /// }
+    ///
+    /// variants!(enum Shape { Circle(f32), Square(f32) }); // Expands to `Circle Square`.
+    Variants,
+
+    /// Replace the item within the [`TokenStream2`] with its generics, as split for an implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// macro_rules! generics {
+    ///     ($item:item) => { permafrost::embed! { [< $item:generics{type} >] } };
+    /// }
+    ///
+    /// generics!(struct Wrapper<T: Clone>(T);); // Expands to `<T>`.
+    Generics,
+
+    /// Replace the item within the [`TokenStream2`] with a list of its outer attributes, each as a `[..]` group.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// macro_rules! attrs {
+    ///     ($item:item) => { permafrost::embed! { [< $item:attrs >] } };
+    /// }
+    ///
+    /// attrs!(#[derive(Debug)] struct Unit;); // Expands to `[derive(Debug)]`.
+    Attrs,
+
+    /// Validate the [`TokenStream2`] as a given syntax node, such as `ty`, `expr`, `path`, `item` or `pat`, re-emitting it as parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  type Buffer = [< "Vec<u8>":unstringify:parse{ty} >]; // Expands to `type Buffer = Vec<u8>;`.
+    /// }
+    Parse,
+
+    /// A user-defined transformation, either declared with `transform name = ...;`, or registered within a [`Registry`](super::Registry).
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// # use permafrost::embed;
+    /// embed! {
+    ///  transform snake_ident = flatten:case{snake}:concatenate;
+    ///
+    ///  [< (HelloWorld) >]:snake_ident; // Expands to `hello_world`.
+    /// }
+    Custom,
+    // TODO: Add more transformations.
+    //
+    // For example:
+    // - `Shuffle` to shuffle the tokens in a token tree.
+    // - `Sort` to sort the tokens in a token tree lexico-graphically. (might want to do this on multiple criteria).
+    // - `Reverse` to reverse the tokens in a token tree. (can be done with macros-by-example but is recursion heavy).
+    // - `Unique` to remove duplicate tokens in a token tree.
+}
+
+/// Represents a pending transformation.
+///
+/// This is used to represent a transformation that has not yet been applied to a [`TokenStream2`].
+#[derive(Debug, Clone)]
+pub struct Transform {
+    pub kind: TransformKind,
+    /// The name the transformation is referred to by.
+    pub name: Ident,
+    /// The unexpanded arguments, these are expanded right before the transformation is applied.
+    pub args: TokenStream2,
+}
+
+/// A transformation chain.
+///
+/// Multiple transformations can be chained together to form a transformation tree,
+/// this allows for powerful high-level transformations to be applied to a token tree.
+///
+/// See the [`TransformKind`] enum for the available transformations.
+#[derive(Debug, Clone)]
+pub enum TransformChain {
+    /// A terminal transformation.
+    End(Transform),
+    /// A non-terminal transformation.
+    Next(Transform, Box<Self>),
+}
+
+impl TransformKind {
+    /// Check whether the name refers to a built-in transformation.
+    pub fn is_builtin(name: &str) -> bool {
+        RECOGNIZED_TRANSFORMS.contains_key(name)
+    }
+
+    /// Recognize the transformation referred to by the name.
+    ///
+    /// Unrecognized names are assumed to be custom transformations, which are only known once expanded.
+    pub fn recognize(name: &Ident) -> Self {
+        RECOGNIZED_TRANSFORMS
+            .get(name.to_string().as_str())
+            .cloned()
+            .unwrap_or(TransformKind::Custom)
+    }
+}
+
+impl IntoIterator for TransformChain {
+    type Item = Transform;
+    type IntoIter = <Vec<Transform> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        /// Recursively append [`Transform`]s to the provided [`Vec`].
+        fn append_to(transforms: &mut Vec<Transform>, chain: TransformChain) {
+            match chain {
+                TransformChain::End(transform) => transforms.push(transform),
+                TransformChain::Next(transform, next) => {
+                    transforms.push(transform);
+
+                    // TODO: use `become` expression when stable
+                    append_to(transforms, *next);
+                }
+            }
+        }
+
+        let mut transforms = Vec::new();
+
+        append_to(&mut transforms, self);
+
+        transforms.into_iter()
+    }
+}
+
+impl TransformChain {
+    /// Check whether the input starts a transformation chain.
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(Token![:]) && (input.peek2(Ident) || input.peek2(Token![match]))
+    }
+
+    /// Expand the transformation chain into a finished token stream.
+    #[inline]
+    pub fn expand(self, tree: TokenStream2, context: &mut Context) -> syn::Result<TokenStream2> {
+        self.into_iter()
+            .try_fold(tree, |acc, transform| transform.apply(acc, context))
+    }
+
+    /// Resolve all aliases within the chain, as visible from the given [`Context`].
+    ///
+    /// The resulting transformations contain no aliases, thus an alias can never refer to itself.
+    pub fn resolve(self, context: &Context) -> syn::Result<Vec<Transform>> {
+        self.into_iter()
+            .try_fold(Vec::new(), |mut transform_list, transform| {
+                match transform.kind {
+                    TransformKind::Custom if !context.registry().contains(&transform.name.to_string()) => {
+                        transform_list.extend(Transform::alias(&transform.name, &transform.args, context)?)
+                    }
+                    _ => transform_list.push(transform),
+                }
+
+                Ok(transform_list)
+            })
+    }
+}
+
+impl Transform {
+    /// Apply the transformation to the input.
+    pub fn apply(self, input: TokenStream2, context: &mut Context) -> syn::Result<TokenStream2> {
+        let Self { kind, name, args } = self;
+
+        // NOTE: The arms of a match may reference its captures, thus these are expanded once selected.
+        if let TransformKind::Match = kind {
+            let (captures, output) = TransformMatch::arms(args).and_then(|arms| TransformMatch::select(input, &arms))?;
+
+            return context.scoped(|context| {
+                for (name, value) in captures {
+                    context.declare(name, value)?;
+                }
+
+                Embed::expand_with(output, context)
+            });
+        }
+
+        if let TransformKind::Custom = kind {
+            if let Some(transformer) = context.registry().get(&name.to_string()) {
+                return transformer.apply(input, Embed::expand_with(args, context)?);
+            }
+
+            return Self::alias(&name, &args, context)?
+                .into_iter()
+                .try_fold(input, |acc, transform| transform.apply(acc, context));
+        }
+
+        let args = Embed::expand_with(args, context)?;
+
+        match kind {
+            TransformKind::Case => TransformCase.apply(input, args),
+            TransformKind::Flatten => TransformFlatten.apply(input, args),
+            TransformKind::Ungroup => TransformUngroup.apply(input, args),
+            TransformKind::Stringify => TransformStringify.apply(input, args),
+            TransformKind::Reverse => TransformReverse.apply(input, args),
+            TransformKind::Append => TransformAppend.apply(input, args),
+            TransformKind::Prefix => TransformPrefix.apply(input, args),
+            TransformKind::Concatenate => TransformConcatenate.apply(input, args),
+            TransformKind::Unstringify => TransformUnstringify.apply(input, args),
+            TransformKind::Count => TransformCount.apply(input, args),
+            TransformKind::Span => TransformSpan.apply(input, args),
+            TransformKind::Hygiene => TransformHygiene.apply(input, args),
+            TransformKind::Gensym => TransformGensym.apply(input, args),
+            TransformKind::IsEmpty => TransformIsEmpty.apply(input, args),
+            TransformKind::IsIdent => TransformIsIdent.apply(input, args),
+            TransformKind::IsLiteral => TransformIsLiteral.apply(input, args),
+            TransformKind::Contains => TransformContains.apply(input, args),
+            TransformKind::Not => TransformNot.apply(input, args),
+            TransformKind::Include => <TransformInclude as Transformate>::new(args).and_then(|args| {
+                context.depend(args.path.clone(), name.span());
+
+                <TransformInclude as Transformate>::apply(input, &args)
+            }),
+            #[cfg(feature = "json")]
+            TransformKind::Json => <TransformJson as Transformate>::new(args).and_then(|args| {
+                context.depend(args.path.clone(), name.span());
+
+                <TransformJson as Transformate>::apply(input, &args)
+            }),
+            #[cfg(not(feature = "json"))]
+            TransformKind::Json => Err(Error::new(name.span(), "transform `json` requires the `json` feature")),
+            #[cfg(feature = "toml")]
+            TransformKind::Toml => <TransformToml as Transformate>::new(args).and_then(|args| {
+                context.depend(args.path.clone(), name.span());
+
+                <TransformToml as Transformate>::apply(input, &args)
+            }),
+            #[cfg(not(feature = "toml"))]
+            TransformKind::Toml => Err(Error::new(name.span(), "transform `toml` requires the `toml` feature")),
+            TransformKind::Csv => <TransformCsv as Transformate>::new(args).and_then(|args| {
+                context.depend(args.path.clone(), name.span());
+
+                <TransformCsv as Transformate>::apply(input, &args)
+            }),
+            TransformKind::Env => <TransformEnv as Transformate>::new(args).and_then(|args| {
+                context.depend_env(args.name.clone(), name.span());
+
+                <TransformEnv as Transformate>::apply(input, &args)
+            }),
+            // NOTE: The name of the transformation is the key of the predicate, thus it is parsed along with the arguments.
+            //
+            // Unlike `env`, predicates are not tracked, as their variables stem from a build script, whose output Cargo tracks on its own.
+            TransformKind::Cfg => <TransformCfg as Transformate>::new(quote!(#name { #args }))
+                .and_then(|args| <TransformCfg as Transformate>::apply(input, &args)),
+            TransformKind::When => <TransformWhen as Transformate>::new(args)
+                .and_then(|args| <TransformWhen as Transformate>::apply(input, &args)),
+            TransformKind::Name => TransformName.apply(input, args),
+            TransformKind::Fields => TransformFields.apply(input, args),
+            TransformKind::Variants => TransformVariants.apply(input, args),
+            TransformKind::Generics => TransformGenerics.apply(input, args),
+            TransformKind::Attrs => TransformAttrs.apply(input, args),
+            TransformKind::Parse => TransformParse.apply(input, args),
+            TransformKind::Match | TransformKind::Custom => unreachable!("handled above"),
+        }
+    }
+
+    /// Look up the transformations an alias stands for.
+    fn alias(name: &Ident, args: &TokenStream2, context: &Context) -> syn::Result<Vec<Self>> {
+        if !args.is_empty() {
+            return Err(Error::new(
+                name.span(),
+                format!("transform `{name}` is declared with `transform`, and thus takes no arguments"),
+            ));
+        }
+
+        context.alias(name).ok_or_else(|| {
+            let available_transforms = RECOGNIZED_TRANSFORMS
+                .keys()
+                .copied()
+                .chain(context.registry().names())
+                .map(|key| format!("`{}`", key))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            Error::new(
+                name.span(),
+                format!(
+                    "unrecognized transform: `{name}`\navailable transforms are: {available_transforms}\n\
+                     or declare it first with `transform {name} = ...;`"
+                ),
+            )
+        })
+    }
+}
+
+impl Parse for TransformChain {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Self::parse_with(input, false)
+    }
+}
+
+impl TransformChain {
+    /// Parse a chain within the head of a regular `if`, `while`, `match` or `for`, e.g. `if [< FLAG_ $x >]:concatenate { .. }`.
+    ///
+    /// There, a trailing brace group is the branch body rather than arguments, unless followed by another brace group or transform.
+    pub fn parse_head(input: ParseStream) -> syn::Result<Self> {
+        Self::parse_with(input, true)
+    }
+
+    fn parse_with(input: ParseStream, is_head: bool) -> syn::Result<Self> {
+        input.parse::<Token![:]>()?;
+
+        // NOTE: Some transforms are named after keywords, e.g. `match`.
+        let name = Ident::parse_any(input)?;
+
+        let kind = TransformKind::recognize(&name);
+
+        let is_args = input.peek(Brace)
+            && (!is_head || {
+                let fork = input.fork();
+
+                fork.parse::<TokenTree2>().is_ok() && (fork.peek(Brace) || TransformChain::peek(&fork))
+            });
+
+        let args = is_args
+            .then(|| input.parse::<TokenTree2>())
+            .transpose()?
+            .map(|tree| match tree {
+                TokenTree2::Group(group) => group.stream(),
+                _ => unreachable!("peeked for a brace, but did not find a group"),
+            })
+            .unwrap_or_default();
+
+        if TransformChain::peek(input) {
+            Ok(TransformChain::Next(
+                Transform { kind, name, args },
+                Box::new(TransformChain::parse_with(input, is_head)?),
+            ))
+        } else {
+            Ok(TransformChain::End(Transform { kind, name, args }))
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::{ParseStream, Parser as _},
+    Error,
+};
+
+use crate::embed::transform::{Fragment, Transformate};
+
+use super::span::joined;
+
+/// A transformation that validates the target [`TokenStream2`] as a given syntax node, re-emitting it as parsed.
+///
+/// See [`Fragment`] for the available syntax nodes. This is useful after `unstringify`, so that a malformed string is reported at the block,
+/// rather than wherever the tokens end up.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     type Buffer = [< "Vec<u8>":unstringify:parse{ty} >]; // Expands to `type Buffer = Vec<u8>;`.
+///
+///     type Broken = [< "Vec<u8":unstringify:parse{ty} >]; // Fails with `expected a type`, pointing at the block.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformParse;
+
+impl Transformate for TransformParse {
+    type Args = Fragment;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        syn::parse2(args)
+    }
+
+    fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        let span = joined(&input);
+
+        (|stream: ParseStream| {
+            let output = args.parse_from(stream)?;
+
+            if !stream.is_empty() {
+                return Err(stream.error("unexpected trailing tokens"));
+            }
+
+            Ok(output)
+        })
+        .parse2(input.clone())
+        .map_err(|err| {
+            Error::new(
+                span,
+                format!("expected {}, found `{input}`: {err}", args.describe()),
+            )
+        })
+    }
+}
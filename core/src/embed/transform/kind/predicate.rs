@@ -0,0 +1,153 @@
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use quote::ToTokens;
+use syn::Error;
+
+use crate::embed::transform::Transformate;
+
+use super::span::joined;
+
+/// A predicate that checks whether the target [`TokenStream2`] is empty.
+///
+/// Like all predicates, this expands to either `true` or `false`, to be used in a condition.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     #if [< [< $($field)* >]:is_empty >] {
+///         #[derive(Default)]
+///         struct Unit;
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformIsEmpty;
+
+/// A predicate that checks whether the target [`TokenStream2`] is a single identifier.
+#[derive(Debug, Clone)]
+pub struct TransformIsIdent;
+
+/// A predicate that checks whether the target [`TokenStream2`] is a single literal.
+#[derive(Debug, Clone)]
+pub struct TransformIsLiteral;
+
+/// A predicate that checks whether the target [`TokenStream2`] contains the given token trees, at any depth.
+#[derive(Debug, Clone)]
+pub struct TransformContains;
+
+/// A predicate that negates the target predicate.
+#[derive(Debug, Clone)]
+pub struct TransformNot;
+
+impl Transformate for TransformIsEmpty {
+    type Args = ();
+
+    fn new(_: TokenStream2) -> Result<Self::Args, Error> {
+        Ok(())
+    }
+
+    fn apply(input: TokenStream2, _: &Self::Args) -> Result<TokenStream2, Error> {
+        Ok(boolean(input.is_empty(), joined(&input)))
+    }
+}
+
+impl Transformate for TransformIsIdent {
+    type Args = ();
+
+    fn new(_: TokenStream2) -> Result<Self::Args, Error> {
+        Ok(())
+    }
+
+    fn apply(input: TokenStream2, _: &Self::Args) -> Result<TokenStream2, Error> {
+        let span = joined(&input);
+
+        let target_list = input.into_iter().collect::<Vec<_>>();
+
+        Ok(boolean(
+            matches!(target_list.as_slice(), [TokenTree2::Ident(_)]),
+            span,
+        ))
+    }
+}
+
+impl Transformate for TransformIsLiteral {
+    type Args = ();
+
+    fn new(_: TokenStream2) -> Result<Self::Args, Error> {
+        Ok(())
+    }
+
+    fn apply(input: TokenStream2, _: &Self::Args) -> Result<TokenStream2, Error> {
+        let span = joined(&input);
+
+        let target_list = input.into_iter().collect::<Vec<_>>();
+
+        Ok(boolean(
+            matches!(target_list.as_slice(), [TokenTree2::Literal(_)]),
+            span,
+        ))
+    }
+}
+
+impl Transformate for TransformContains {
+    type Args = Vec<String>;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        if args.is_empty() {
+            return Err(Error::new(
+                Span::call_site(),
+                "expected the token trees to search for, e.g. `contains{foo}`",
+            ));
+        }
+
+        Ok(args.into_iter().map(|tree| tree.to_string()).collect())
+    }
+
+    fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        /// Recursively search for the needle, comparing token trees by their string representation.
+        fn contains(input: TokenStream2, needle: &[String]) -> bool {
+            let haystack = input.into_iter().collect::<Vec<_>>();
+
+            haystack.windows(needle.len()).any(|window| {
+                window
+                    .iter()
+                    .zip(needle)
+                    .all(|(tree, target)| tree.to_string() == *target)
+            }) || haystack.into_iter().any(|tree| match tree {
+                TokenTree2::Group(group) => contains(group.stream(), needle),
+                _ => false,
+            })
+        }
+
+        let span = joined(&input);
+
+        Ok(boolean(contains(input, args), span))
+    }
+}
+
+impl Transformate for TransformNot {
+    type Args = ();
+
+    fn new(_: TokenStream2) -> Result<Self::Args, Error> {
+        Ok(())
+    }
+
+    fn apply(input: TokenStream2, _: &Self::Args) -> Result<TokenStream2, Error> {
+        let span = joined(&input);
+
+        match input.clone().into_iter().collect::<Vec<_>>().as_slice() {
+            [TokenTree2::Ident(ident)] if ident == "true" => Ok(boolean(false, span)),
+            [TokenTree2::Ident(ident)] if ident == "false" => Ok(boolean(true, span)),
+            _ => Err(Error::new(
+                span,
+                format!("expected `true` or `false` to negate, found `{input}`"),
+            )),
+        }
+    }
+}
+
+/// Turn a boolean into a `true` or `false` token, with the given span.
+pub fn boolean(value: bool, span: Span) -> TokenStream2 {
+    Ident::new(if value { "true" } else { "false" }, span).into_token_stream()
+}
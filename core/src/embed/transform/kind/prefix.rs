@@ -18,7 +18,7 @@ impl Transformate for TransformPrefix {
         Ok(
             args.clone()
                 .into_iter()
-                .chain(input.into_iter())
+                .chain(input)
                 .collect::<TokenStream2>(),
         )
     }
@@ -0,0 +1,327 @@
+use phf::{phf_map, Map};
+use proc_macro2::{
+    Delimiter, Group, Literal, Span, TokenStream as TokenStream2, TokenTree as TokenTree2,
+};
+use quote::ToTokens as _;
+use syn::{
+    ext::IdentExt as _, parse::Parser as _, Attribute, Error, Fields, FnArg, Generics, Ident, Item,
+};
+
+use crate::embed::transform::Transformate;
+
+use super::span::joined;
+
+static RECOGNIZED_FIELDS: Map<&str, Field> = phf_map! {
+    "pairs" => Field::Pairs,
+    "names" => Field::Names,
+    "types" => Field::Types,
+};
+
+static RECOGNIZED_GENERICS: Map<&str, Generic> = phf_map! {
+    "impl" => Generic::Impl,
+    "type" => Generic::Type,
+    "where" => Generic::Where,
+};
+
+/// A transformation that replaces the item within the target [`TokenStream2`] with its name.
+///
+/// Like all reflection transformers, this parses the target as a single item, e.g. a `$item:item` fragment of `macro_rules!`,
+/// and supports structs, enums, unions, functions, traits and type aliases.
+///
+/// An item written directly within a block is limited, as a `>` outside of any group closes the block,
+/// and a `:` followed by an identifier starts a chain, e.g. `[< struct Wrapper<T: Clone>(T); >]` fails to parse.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// macro_rules! getters {
+///     ($item:item) => {
+///         permafrost::embed! {
+///             $item
+///
+///             impl [< $item:generics >] [< $item:name >] [< $item:generics{type} >] [< $item:generics{where} >] {
+///                 for #field in [< $item:fields >] {
+///                     [< #field:match{ ((#name:tt #ty:tt)) => {
+///                         pub fn [< get_ #name >]:concatenate{ident}(&self) -> &#ty { &self.#name }
+///                     } } >]
+///                 }
+///             }
+///         }
+///     };
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformName;
+
+/// A transformation that replaces the item within the target [`TokenStream2`] with a list of its fields.
+///
+/// The fields of structs and unions are listed, as well as the typed parameters of functions, while `self` is skipped.
+/// The fields of a tuple struct are named by their index.
+#[derive(Debug, Clone)]
+pub struct TransformFields;
+
+/// A transformation that replaces the enum within the target [`TokenStream2`] with a list of its variant names.
+#[derive(Debug, Clone)]
+pub struct TransformVariants;
+
+/// A transformation that replaces the item within the target [`TokenStream2`] with its generics, as split for an implementation.
+#[derive(Debug, Clone)]
+pub struct TransformGenerics;
+
+/// A transformation that replaces the item within the target [`TokenStream2`] with a list of its outer attributes.
+///
+/// Every attribute turns into a `[..]` group, such that `# #attr` re-emits it.
+#[derive(Debug, Clone)]
+pub struct TransformAttrs;
+
+/// The selected representation of the fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    /// A `(name type)` group per field (`default mode`).
+    Pairs,
+    /// The name of each field.
+    Names,
+    /// The type of each field, as a single token tree.
+    Types,
+}
+
+/// The selected part of the generics, as returned by [`Generics::split_for_impl`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Generic {
+    /// The parameters along with their bounds, e.g. `<T: Clone>` (`default mode`).
+    Impl,
+    /// The parameters without their bounds, e.g. `<T>`.
+    Type,
+    /// The where clause, if any.
+    Where,
+}
+
+impl Transformate for TransformName {
+    type Args = ();
+
+    fn new(_: TokenStream2) -> Result<Self::Args, Error> {
+        Ok(())
+    }
+
+    fn apply(input: TokenStream2, _: &Self::Args) -> Result<TokenStream2, Error> {
+        let item = parse(input)?;
+
+        Ok(parts(&item)?.1.into_token_stream())
+    }
+}
+
+impl Transformate for TransformFields {
+    type Args = Field;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        mode(args, &RECOGNIZED_FIELDS, Field::Pairs)
+    }
+
+    fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        let span = joined(&input);
+
+        let field_list = match parse(input)? {
+            Item::Struct(item) => fields(&item.fields),
+            Item::Union(item) => fields(&Fields::Named(item.fields)),
+            Item::Fn(item) => item
+                .sig
+                .inputs
+                .iter()
+                .filter_map(|input| match input {
+                    FnArg::Typed(target) => {
+                        Some((target.pat.to_token_stream(), target.ty.to_token_stream()))
+                    }
+                    FnArg::Receiver(_) => None,
+                })
+                .collect(),
+            _ => {
+                return Err(Error::new(
+                    span,
+                    "expected a struct, union or function to list the fields of",
+                ))
+            }
+        };
+
+        Ok(field_list
+            .into_iter()
+            .map(|(name, ty)| match args {
+                Field::Pairs => group(
+                    Delimiter::Parenthesis,
+                    [single(name), single(ty)].into_iter().collect(),
+                ),
+                Field::Names => single(name),
+                Field::Types => single(ty),
+            })
+            .collect())
+    }
+}
+
+impl Transformate for TransformVariants {
+    type Args = ();
+
+    fn new(_: TokenStream2) -> Result<Self::Args, Error> {
+        Ok(())
+    }
+
+    fn apply(input: TokenStream2, _: &Self::Args) -> Result<TokenStream2, Error> {
+        let span = joined(&input);
+
+        match parse(input)? {
+            Item::Enum(item) => Ok(item
+                .variants
+                .into_iter()
+                .map(|variant| variant.ident.into_token_stream())
+                .collect()),
+            _ => Err(Error::new(span, "expected an enum to list the variants of")),
+        }
+    }
+}
+
+impl Transformate for TransformGenerics {
+    type Args = Generic;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        mode(args, &RECOGNIZED_GENERICS, Generic::Impl)
+    }
+
+    fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        let item = parse(input)?;
+
+        let (impl_generics, type_generics, where_clause) = parts(&item)?.2.split_for_impl();
+
+        Ok(match args {
+            Generic::Impl => impl_generics.into_token_stream(),
+            Generic::Type => type_generics.into_token_stream(),
+            Generic::Where => where_clause.into_token_stream(),
+        })
+    }
+}
+
+impl Transformate for TransformAttrs {
+    type Args = ();
+
+    fn new(_: TokenStream2) -> Result<Self::Args, Error> {
+        Ok(())
+    }
+
+    fn apply(input: TokenStream2, _: &Self::Args) -> Result<TokenStream2, Error> {
+        let item = parse(input)?;
+
+        Ok(parts(&item)?
+            .0
+            .iter()
+            .map(|attribute| {
+                let mut target_group =
+                    Group::new(Delimiter::Bracket, attribute.meta.to_token_stream());
+
+                target_group.set_span(attribute.bracket_token.span.join());
+
+                TokenTree2::Group(target_group)
+            })
+            .collect())
+    }
+}
+
+/// Parse the target as a single item, looking through the invisible group of a `$item:item` fragment.
+fn parse(input: TokenStream2) -> Result<Item, Error> {
+    let span = joined(&input);
+
+    let input = match input.clone().into_iter().collect::<Vec<_>>().as_slice() {
+        [TokenTree2::Group(group)] if group.delimiter() == Delimiter::None => group.stream(),
+        _ => input,
+    };
+
+    syn::parse2(input).map_err(|err| {
+        Error::new(
+            span,
+            format!("expected an item, e.g. a struct, enum or function: {err}"),
+        )
+    })
+}
+
+/// Get the attributes, name and generics of an item.
+fn parts(item: &Item) -> Result<(&[Attribute], &Ident, &Generics), Error> {
+    match item {
+        Item::Struct(item) => Ok((&item.attrs, &item.ident, &item.generics)),
+        Item::Enum(item) => Ok((&item.attrs, &item.ident, &item.generics)),
+        Item::Union(item) => Ok((&item.attrs, &item.ident, &item.generics)),
+        Item::Fn(item) => Ok((&item.attrs, &item.sig.ident, &item.sig.generics)),
+        Item::Trait(item) => Ok((&item.attrs, &item.ident, &item.generics)),
+        Item::Type(item) => Ok((&item.attrs, &item.ident, &item.generics)),
+        item => Err(Error::new(
+            joined(&item.to_token_stream()),
+            "expected a struct, enum, union, function, trait or type alias",
+        )),
+    }
+}
+
+/// Get the name and type of every field, naming the fields of a tuple struct by their index.
+fn fields(fields: &Fields) -> Vec<(TokenStream2, TokenStream2)> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let name = field.ident.as_ref().map_or_else(
+                || {
+                    let mut target_literal = Literal::usize_unsuffixed(index);
+
+                    target_literal.set_span(
+                        field
+                            .ty
+                            .to_token_stream()
+                            .into_iter()
+                            .next()
+                            .map_or_else(Span::call_site, |tree| tree.span()),
+                    );
+
+                    target_literal.into_token_stream()
+                },
+                |ident| ident.to_token_stream(),
+            );
+
+            (name, field.ty.to_token_stream())
+        })
+        .collect()
+}
+
+/// Parse an optional mode, falling back to the given default.
+fn mode<T: Copy>(args: TokenStream2, modes: &Map<&str, T>, default: T) -> Result<T, Error> {
+    if args.is_empty() {
+        return Ok(default);
+    }
+
+    // NOTE: Modes such as `impl` and `where` are keywords.
+    let target_ident = Ident::parse_any.parse2(args)?;
+
+    let target_repr = target_ident.to_string();
+
+    modes.get(target_repr.as_str()).copied().ok_or_else(|| {
+        Error::new(
+            target_ident.span(),
+            format!(
+                "unknown mode: `{target_repr}`, valid modes are: {modes}",
+                modes = modes.keys().copied().collect::<Vec<&str>>().join(" ")
+            ),
+        )
+    })
+}
+
+/// Create a group with the span of its contents.
+fn group(delimiter: Delimiter, stream: TokenStream2) -> TokenTree2 {
+    let mut target_group = Group::new(delimiter, stream);
+
+    target_group.set_span(joined(&target_group.stream()));
+
+    TokenTree2::Group(target_group)
+}
+
+/// Keep a stream as a single token tree, so that it is a single element of a list.
+fn single(stream: TokenStream2) -> TokenTree2 {
+    let mut iter = stream.clone().into_iter();
+
+    match (iter.next(), iter.next()) {
+        (Some(tree), None) => tree,
+        _ => group(Delimiter::None, stream),
+    }
+}
@@ -0,0 +1,122 @@
+use phf::{phf_map, Map};
+use proc_macro2::{Group as Group2, Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use syn::{Error, Ident};
+
+use crate::embed::transform::Transformate;
+
+static RECOGNIZED_MODES: Map<&str, Respan> = phf_map! {
+    "call_site" => Respan::CallSite,
+    "mixed_site" => Respan::MixedSite,
+    "first" => Respan::First,
+    "last" => Respan::Last,
+};
+
+/// A transformation that re-spans every token in the target [`TokenStream2`].
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     struct [< hello world >]:span{last}:concatenate; // Expands to `struct helloworld;`, pointing at `world`.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformSpan;
+
+/// The selected span for re-spanning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Respan {
+    /// Use [`Span::call_site`].
+    CallSite,
+
+    /// Use [`Span::mixed_site`].
+    MixedSite,
+
+    /// Use the span of the first token in the target stream.
+    First,
+
+    /// Use the span of the last token in the target stream.
+    Last,
+}
+
+impl Transformate for TransformSpan {
+    type Args = Respan;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        let target_ident: Ident = syn::parse2(args)?;
+
+        let target_repr = target_ident.to_string();
+
+        RECOGNIZED_MODES
+            .get(target_repr.as_str())
+            .copied()
+            .ok_or_else(|| {
+                Error::new(
+                    target_ident.span(),
+                    format!(
+                        "unknown span: `{target_repr}`, valid spans are: {modes}",
+                        modes = RECOGNIZED_MODES
+                            .keys()
+                            .copied()
+                            .collect::<Vec<&str>>()
+                            .join(" ")
+                    ),
+                )
+            })
+    }
+
+    fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        let target_span = match args {
+            Respan::CallSite => Span::call_site(),
+            Respan::MixedSite => Span::mixed_site(),
+            Respan::First => match input.clone().into_iter().next() {
+                Some(first) => first.span(),
+                None => return Ok(input),
+            },
+            Respan::Last => match input.clone().into_iter().last() {
+                Some(last) => last.span(),
+                None => return Ok(input),
+            },
+        };
+
+        Ok(respan(input, target_span))
+    }
+}
+
+/// Compute the span covering the whole target [`TokenStream2`].
+///
+/// The first and last token spans are joined where the compiler allows it, otherwise this
+/// falls back to the span of the first token, and to [`Span::call_site`] for an empty stream.
+pub fn joined(input: &TokenStream2) -> Span {
+    let mut iter = input.clone().into_iter();
+
+    let Some(first) = iter.next() else {
+        return Span::call_site();
+    };
+
+    iter.last()
+        .and_then(|last| first.span().join(last.span()))
+        .unwrap_or_else(|| first.span())
+}
+
+/// Recursively set the span of every token in the target [`TokenStream2`].
+pub fn respan(input: TokenStream2, span: Span) -> TokenStream2 {
+    input
+        .into_iter()
+        .map(|target_tree| match target_tree {
+            TokenTree2::Group(group) => {
+                let mut new_group = Group2::new(group.delimiter(), respan(group.stream(), span));
+
+                new_group.set_span(span);
+
+                TokenTree2::Group(new_group)
+            }
+            mut target_tree => {
+                target_tree.set_span(span);
+
+                target_tree
+            }
+        })
+        .collect()
+}
@@ -4,6 +4,8 @@ use proc_macro2::TokenStream as TokenStream2;
 
 use crate::embed::transform::Transformate;
 
+use super::span::{joined, respan};
+
 /// A transformation that stringifies the target [`TokenStream2`].
 ///
 /// This transformation is useful for when you want to convert the target [`TokenStream2`] into a string.
@@ -40,7 +42,9 @@ impl Transformate for TransformStringify {
     }
 
     fn apply(input: TokenStream2, _: &Self::Args) -> Result<TokenStream2, syn::Error> {
-        let target_output = dbg!(input.to_string());
+        let span = joined(&input);
+
+        let target_output = input.to_string();
 
         let mut separator = String::from('#');
 
@@ -49,6 +53,7 @@ impl Transformate for TransformStringify {
         }
 
         TokenStream2::from_str(&format!("r{separator}\"{target_output}\"{separator}"))
+            .map(|output| respan(output, span))
             .map_err(Into::into)
     }
 }
@@ -0,0 +1,104 @@
+use std::{env, path::Path};
+
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::{ParseStream, Parser as _},
+    Error, Ident,
+};
+use toml::Value;
+
+use crate::embed::transform::Transformate;
+
+use super::{
+    data::{Data, SourceArgs},
+    include::read,
+};
+
+/// A transformation that appends a value of a TOML file to the target [`TokenStream2`].
+///
+/// The path is resolved relative to the directory of the invoking crate's manifest, and tracked like [`TransformInclude`](super::include::TransformInclude).
+/// Instead of a path, `metadata` selects the `[package.metadata]` table of the invoking crate's `Cargo.toml`.
+///
+/// The value is selected with a query of dot-separated keys and `[index]` accessors, e.g. `"protocol.versions[0]"`,
+/// the whole document is selected if no query is given. The value is then converted into tokens, see [`Data::to_list`].
+///
+/// Date-times turn into string literals.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// // Cargo.toml: [package.metadata.protocol] version = 3
+/// embed! {
+///     const PROTOCOL_VERSION: u8 = [< :toml{metadata, "protocol.version"} >]; // Expands to `3`.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformToml;
+
+impl Transformate for TransformToml {
+    type Args = SourceArgs;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        (|input: ParseStream| {
+            if !input.peek(Ident) {
+                return SourceArgs::parse(input);
+            }
+
+            let target_ident = input.parse::<Ident>()?;
+
+            if target_ident != "metadata" {
+                return Err(Error::new(
+                    target_ident.span(),
+                    format!("unknown source: `{target_ident}`, expected a path or `metadata`"),
+                ));
+            }
+
+            let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").ok_or_else(|| {
+                Error::new(
+                    target_ident.span(),
+                    "cannot locate the manifest, as `CARGO_MANIFEST_DIR` is not set",
+                )
+            })?;
+
+            Ok(SourceArgs {
+                path: Path::new(&manifest_dir).join("Cargo.toml"),
+                span: target_ident.span(),
+                query: format!("package.metadata.{}", SourceArgs::query(input)?),
+            })
+        })
+        .parse2(args)
+    }
+
+    fn apply(mut input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        let document = toml::from_str::<Value>(&read(&args.path, args.span)?).map_err(|err| {
+            Error::new(
+                args.span,
+                format!("failed to parse `{}` as TOML: {err}", args.path.display()),
+            )
+        })?;
+
+        input.extend(Data::from(document).select_from(args)?.to_list(args.span));
+
+        Ok(input)
+    }
+}
+
+impl From<Value> for Data {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::String(value) => Self::String(value),
+            Value::Integer(value) => Self::Integer(value.into()),
+            Value::Float(value) => Self::Float(value),
+            Value::Boolean(value) => Self::Boolean(value),
+            Value::Datetime(value) => Self::String(value.to_string()),
+            Value::Array(element_list) => Self::Array(element_list.into_iter().map(Self::from).collect()),
+            Value::Table(entry_map) => Self::Table(
+                entry_map
+                    .into_iter()
+                    .map(|(key, value)| (key, Self::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
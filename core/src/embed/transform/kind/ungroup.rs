@@ -0,0 +1,122 @@
+use phf::{phf_map, Map};
+use proc_macro2::{Delimiter, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use quote::ToTokens;
+use syn::{
+    parse::{ParseStream, Parser as _},
+    Error, Ident, Token,
+};
+
+use crate::embed::transform::Transformate;
+
+static RECOGNIZED_DELIMITERS: Map<&str, Delimiter> = phf_map! {
+    "paren" => Delimiter::Parenthesis,
+    "bracket" => Delimiter::Bracket,
+    "brace" => Delimiter::Brace,
+    "none" => Delimiter::None,
+};
+
+/// A de-grouping transformation.
+///
+/// From a [`TokenStream2`] that contains a [`TokenTree2::Group`], this transformation will de-group the contents of the group.
+///
+/// This is useful for when you want to accept an embedded [`TokenStream2`] atomically, to then pass it to another transformation.
+///
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use tokel::embed;
+///
+///
+/// macro_rules! unexpected {
+///    ($($tt:tt)*) => {
+///       embed! {
+///         compile_error!([< ($($tt)*):ungroup:stringify >])
+///      }
+///   };
+/// }
+///
+/// unexpected! {
+///   insert racial slur here
+/// } // Expands to `compile_error!("insert racial slur here")`.
+///
+/// ```
+///
+/// Only the groups of the given delimiters are de-grouped, if any are given, e.g. `ungroup{none}` strips the invisible groups
+/// around `macro_rules!` fragments, while keeping any brackets.
+#[derive(Debug, Clone)]
+pub struct TransformUngroup;
+
+/// A selection of delimiters, where an empty selection selects all of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Delimiters(Vec<Delimiter>);
+
+impl Delimiters {
+    /// Parse a list of delimiters, optionally separated by commas, up until the end of the input.
+    pub fn parse(input: ParseStream) -> Result<Self, Error> {
+        let mut target_delimiters = Self::default();
+
+        while !input.is_empty() {
+            target_delimiters.push(input)?;
+
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        Ok(target_delimiters)
+    }
+
+    /// Parse a single delimiter, and select it.
+    pub fn push(&mut self, input: ParseStream) -> Result<(), Error> {
+        let target_ident = input.parse::<Ident>()?;
+
+        let target_repr = target_ident.to_string();
+
+        let Some(delimiter) = RECOGNIZED_DELIMITERS.get(target_repr.as_str()).copied() else {
+            return Err(Error::new(
+                target_ident.span(),
+                format!(
+                    "unknown delimiter: `{target_repr}`, valid delimiters are: {delimiters}",
+                    delimiters = RECOGNIZED_DELIMITERS
+                        .keys()
+                        .copied()
+                        .collect::<Vec<&str>>()
+                        .join(" ")
+                ),
+            ));
+        };
+
+        self.0.push(delimiter);
+
+        Ok(())
+    }
+
+    /// Check whether the delimiter is selected.
+    pub fn contains(&self, delimiter: Delimiter) -> bool {
+        self.0.is_empty() || self.0.contains(&delimiter)
+    }
+}
+
+impl Transformate for TransformUngroup {
+    type Args = Delimiters;
+
+    #[inline]
+    fn new(args: TokenStream2) -> Result<Self::Args, syn::Error> {
+        Delimiters::parse.parse2(args)
+    }
+
+    #[inline]
+    fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, syn::Error> {
+        Ok(input
+            .into_iter()
+            .fold(TokenStream2::new(), |mut acc, target_tree| {
+                let target_output = match target_tree {
+                    TokenTree2::Group(group) if args.contains(group.delimiter()) => group.stream(),
+                    _ => target_tree.into_token_stream(),
+                };
+
+                acc.extend(target_output);
+
+                acc
+            }))
+    }
+}
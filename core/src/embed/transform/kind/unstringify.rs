@@ -0,0 +1,126 @@
+use std::str::FromStr;
+
+use phf::{phf_map, Map};
+use proc_macro2::{Group as Group2, Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+use quote::ToTokens;
+use syn::{Error, Ident, Lit};
+
+use crate::embed::transform::Transformate;
+
+use super::span::respan;
+
+static RECOGNIZED_MODES: Map<&str, Unstringify> = phf_map! {
+    "shallow" => Unstringify::Shallow,
+    "recursive" => Unstringify::Recursive,
+};
+
+/// A transformation that turns the string literals within the target [`TokenStream2`] into the tokens they contain.
+///
+/// Regular, raw, byte and C string literals are supported, as long as their contents are valid UTF-8.
+/// Any other token is kept as is.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use permafrost::embed;
+/// embed! {
+///     [< "hello" b"world" >]:unstringify; // Expands to `hello world`.
+///
+///     [< ("hello") >]:unstringify{recursive}; // Expands to `(hello)`.
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformUnstringify;
+
+/// The selected depth of unstringification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unstringify {
+    /// Only unstringify the top-level literals (`default mode`).
+    Shallow,
+    /// Also unstringify the literals within groups, at any depth.
+    Recursive,
+}
+
+impl Transformate for TransformUnstringify {
+    type Args = Unstringify;
+
+    fn new(args: TokenStream2) -> Result<Self::Args, Error> {
+        if args.is_empty() {
+            return Ok(Unstringify::Shallow);
+        }
+
+        let target_ident: Ident = syn::parse2(args)?;
+
+        let target_repr = target_ident.to_string();
+
+        RECOGNIZED_MODES
+            .get(target_repr.as_str())
+            .copied()
+            .ok_or_else(|| {
+                Error::new(
+                    target_ident.span(),
+                    format!(
+                        "unknown mode: `{target_repr}`, valid modes are: {modes}",
+                        modes = RECOGNIZED_MODES
+                            .keys()
+                            .copied()
+                            .collect::<Vec<&str>>()
+                            .join(" ")
+                    ),
+                )
+            })
+    }
+
+    fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, Error> {
+        input
+            .into_iter()
+            .try_fold(TokenStream2::new(), |mut acc, target_tree| {
+                match target_tree {
+                    TokenTree2::Literal(lit) => {
+                        match syn::parse2::<Lit>(lit.into_token_stream())? {
+                            Lit::Str(target_str) => {
+                                acc.extend(tokenize(&target_str.value(), target_str.span())?)
+                            }
+                            Lit::ByteStr(target_str) => acc.extend(tokenize(
+                                &utf8(target_str.value(), target_str.span())?,
+                                target_str.span(),
+                            )?),
+                            Lit::CStr(target_str) => acc.extend(tokenize(
+                                &utf8(target_str.value().into_bytes(), target_str.span())?,
+                                target_str.span(),
+                            )?),
+                            lit => acc.extend(lit.into_token_stream()),
+                        }
+                    }
+                    TokenTree2::Group(group) if *args == Unstringify::Recursive => {
+                        let mut target_group =
+                            Group2::new(group.delimiter(), Self::apply(group.stream(), args)?);
+
+                        target_group.set_span(group.span());
+
+                        acc.extend(core::iter::once(TokenTree2::Group(target_group)));
+                    }
+                    _ => acc.extend(core::iter::once(target_tree)),
+                };
+
+                Ok(acc)
+            })
+    }
+}
+
+/// Lex the contents of a string literal, reporting a failure at the literal.
+fn tokenize(contents: &str, span: Span) -> Result<TokenStream2, Error> {
+    TokenStream2::from_str(contents)
+        .map(|stream| respan(stream, span))
+        .map_err(|err| Error::new(span, format!("failed to unstringify `{contents}`: {err}")))
+}
+
+/// Decode the contents of a byte or C string literal as UTF-8.
+fn utf8(contents: Vec<u8>, span: Span) -> Result<String, Error> {
+    String::from_utf8(contents).map_err(|err| {
+        Error::new(
+            span,
+            format!("failed to unstringify a literal, as its contents are not valid UTF-8: {err}"),
+        )
+    })
+}
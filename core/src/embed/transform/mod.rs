@@ -0,0 +1,11 @@
+mod fragment;
+mod transformate;
+mod kind;
+mod registry;
+mod segment;
+
+pub use fragment::Fragment;
+pub use segment::{Segment, SegmentModified, SegmentUntouched};
+pub use kind::{Transform, TransformChain, TransformKind};
+pub use registry::Registry;
+pub use transformate::{Transformate, Transformer};
@@ -0,0 +1,70 @@
+use std::{collections::HashMap, fmt, rc::Rc};
+
+use super::{TransformKind, Transformer};
+
+/// A set of third-party transformers, available alongside the built-in ones.
+///
+/// This allows a downstream procedural macro to provide its own `embed!`-like macro,
+/// with additional transformers, while reusing the parser, error reporting and all built-in transformers.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// #[proc_macro]
+/// pub fn my_embed(input: TokenStream) -> TokenStream {
+///     let registry = Registry::default().register("namespaced", Namespaced);
+///
+///     permafrost_core::expand_with(input.into(), registry)
+///         .unwrap_or_else(|err| err.into_compile_error())
+///         .into()
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct Registry {
+    /// The registered transformers, by name.
+    transformer_map: HashMap<String, Rc<dyn Transformer>>,
+}
+
+impl Registry {
+    /// Register a transformer under the given name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name is already taken, either by a built-in or a previously registered transformer.
+    pub fn register(mut self, name: &str, transformer: impl Transformer + 'static) -> Self {
+        assert!(
+            !TransformKind::is_builtin(name),
+            "transform `{name}` shadows a built-in transform, choose a different name"
+        );
+
+        assert!(
+            !self.transformer_map.contains_key(name),
+            "transform `{name}` is already registered"
+        );
+
+        self.transformer_map.insert(name.to_string(), Rc::new(transformer));
+
+        self
+    }
+
+    /// Look up a registered transformer.
+    pub fn get(&self, name: &str) -> Option<Rc<dyn Transformer>> {
+        self.transformer_map.get(name).cloned()
+    }
+
+    /// Check whether a transformer is registered under the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.transformer_map.contains_key(name)
+    }
+
+    /// The names of all registered transformers.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.transformer_map.keys().map(String::as_str)
+    }
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.names()).finish()
+    }
+}
@@ -0,0 +1,95 @@
+pub use proc_macro2::TokenStream as TokenStream2;
+use syn::parse::{Parse, ParseStream};
+
+use crate::embed::{context::Context, sequence::Sequence};
+
+use super::TransformChain;
+
+/// A segment in a transformation.
+///
+/// This can either be a modified segment or an untouched segment.
+#[derive(Debug, Clone)]
+pub enum Segment {
+    /// A segment that has been modified.
+    ///
+    /// This represents a transformation.
+    Modified(SegmentModified),
+    /// A segment that is untouched.
+    ///
+    /// This represents a no-op transformation.
+    Untouched(SegmentUntouched),
+}
+
+/// A modified segment.
+///
+/// This represents a transformation to a single [`Sequence`].
+#[derive(Debug, Clone)]
+pub struct SegmentModified {
+    /// The sequence to apply the transformation to, or [`None`] to apply it to an empty target, e.g. `[< :include{..} >]`.
+    sequence: Option<Sequence>,
+    /// The transformation to apply to the token tree.
+    chain: TransformChain,
+}
+
+/// An untouched [`Sequence`].
+///
+/// This is a no-op transformation.
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct SegmentUntouched(Sequence);
+
+impl Segment {
+    /// Expand the segment into a finished token stream.
+    #[inline]
+    pub fn expand(self, context: &mut Context) -> syn::Result<TokenStream2> {
+        match self {
+            Self::Modified(modified) => modified.expand(context),
+            Self::Untouched(untouched) => untouched.0.expand(context),
+        }
+    }
+}
+
+impl Parse for Segment {
+    #[inline]
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // NOTE: A leading chain has no target, which is useful for transformations that produce tokens on their own.
+        if TransformChain::peek(input) {
+            let chain = input.parse()?;
+
+            return Ok(Self::Modified(SegmentModified { sequence: None, chain }));
+        }
+
+        let sequence: Sequence = input.parse()?;
+
+        // NOTE: A block already consumes its own chain, which is equivalent to a segment chain.
+        let is_chainable = !matches!(sequence, Sequence::Transform { .. });
+
+        let target_segment = if is_chainable && TransformChain::peek(input) {
+            let chain = input.parse()?;
+
+            Self::Modified(SegmentModified {
+                sequence: Some(sequence),
+                chain,
+            })
+        } else {
+            Self::Untouched(SegmentUntouched(sequence))
+        };
+
+        Ok(target_segment)
+    }
+}
+
+impl SegmentModified {
+    /// Expand the segment into a finished token stream.
+    #[inline]
+    pub fn expand(self, context: &mut Context) -> syn::Result<TokenStream2> {
+        let Self { sequence, chain } = self;
+
+        let tree = sequence
+            .map(|sequence| sequence.expand(context))
+            .transpose()?
+            .unwrap_or_default();
+
+        chain.expand(tree, context)
+    }
+}
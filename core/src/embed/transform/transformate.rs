@@ -0,0 +1,61 @@
+use std::error::Error;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+/// A trait for individual transformations.
+/// 
+/// A [`Transformate`] is a possibly-recursive transformation, that can be applied to a [`TokenStream2`].
+/// 
+/// These are the building blocks for this crate, and are used to transform the input token tree.
+/// 
+/// These are stricly pure and do not have any side-effects.
+pub trait Transformate<E = syn::Error>
+where
+    E: Error,
+{
+    /// The arguments required for this transformation.
+    /// 
+    /// This is not left to the implementor to decide as the transformation can be unsized.
+    type Args;
+
+    /// Parse the arguments required for this transformation.
+    /// 
+    /// See [`Transformate::Args`] for more.
+    fn new(args: TokenStream2) -> Result<Self::Args, E>;
+
+    /// Apply the transformation to the target stream, with the given arguments.
+    fn apply(input: TokenStream2, args: &Self::Args) -> Result<TokenStream2, E>;
+}
+
+/// An object-safe counterpart of [`Transformate`], to be registered within a [`Registry`](super::Registry).
+///
+/// Unlike [`Transformate`], the arguments are parsed on every application, which allows for heterogeneous transformers
+/// to be stored side by side. Every [`Transformate`] is a [`Transformer`].
+///
+/// # Example
+///
+/// ```rust, ignore
+/// use permafrost_core::embed::transform::Transformer;
+///
+/// /// Prefix every identifier with `my_`.
+/// struct Namespaced;
+///
+/// impl Transformer for Namespaced {
+///     fn apply(&self, input: TokenStream2, _: TokenStream2) -> syn::Result<TokenStream2> {
+///         // ..
+///     }
+/// }
+/// ```
+pub trait Transformer {
+    /// Apply the transformation to the target stream, with the given, already expanded, arguments.
+    fn apply(&self, input: TokenStream2, args: TokenStream2) -> syn::Result<TokenStream2>;
+}
+
+impl<T> Transformer for T
+where
+    T: Transformate,
+{
+    fn apply(&self, input: TokenStream2, args: TokenStream2) -> syn::Result<TokenStream2> {
+        <T as Transformate>::new(args).and_then(|args| <T as Transformate>::apply(input, &args))
+    }
+}
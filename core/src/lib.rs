@@ -0,0 +1,120 @@
+//! The transformation engine behind the `permafrost` crate.
+//!
+//! Unlike `permafrost-derive`, this is a regular library, thus it can be used from build scripts,
+//! other procedural macros and tests alike.
+//!
+//! # Example
+//!
+//! ```rust, ignore
+//! use quote::quote;
+//!
+//! let output = permafrost_core::expand(quote! {
+//!     struct [< foo bar >]:case{pascal}:concatenate;
+//! })?;
+//!
+//! assert_eq!(output.to_string(), "struct FooBar ;");
+//! ```
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote_spanned, ToTokens as _};
+use syn::{Error, Ident, LitStr};
+
+pub mod embed;
+
+pub use embed::{
+    context::Context,
+    transform::{Registry, Transformate, Transformer},
+    Embed,
+};
+
+/// Expand a token stream, as if it were the input of `embed!`.
+///
+/// See [`Embed::recursively_expand`].
+#[inline]
+pub fn expand(input: TokenStream2) -> syn::Result<TokenStream2> {
+    Embed::recursively_expand(input)
+}
+
+/// Expand a token stream, as if it were the input of `embed!`, with the given third-party transformers available.
+///
+/// See [`Registry`].
+#[inline]
+pub fn expand_with(input: TokenStream2, registry: Registry) -> syn::Result<TokenStream2> {
+    let mut context = Context::new(registry);
+
+    Embed::expand_with(input, &mut context).and_then(|output| context.finish(output))
+}
+
+/// Expand exactly one block into a single string literal.
+///
+/// If the block reads a file or an environment variable, the literal is wrapped into a block expression
+/// that tracks it, e.g. `{ const _: () = { .. }; "contents" }`, see [`Context::tracking`].
+///
+/// This is the engine behind `embed_str!`.
+#[inline]
+pub fn expand_str(input: TokenStream2) -> syn::Result<TokenStream2> {
+    let (span, output, context) = expand_block(input)?;
+
+    let target_literal = syn::parse2::<LitStr>(output.clone()).map_err(|_| {
+        Error::new(
+            span,
+            format!(
+                "expected the block to expand to a single string literal, found `{output}`\n\
+                 consider appending `:concatenate{{string}}` to the block"
+            ),
+        )
+    })?;
+
+    Ok(match context.tracking() {
+        Some(tracking) => quote_spanned!(span => { #tracking #target_literal }),
+        None => target_literal.into_token_stream(),
+    })
+}
+
+/// Expand exactly one block into a single identifier.
+///
+/// This is the engine behind `embed_ident!`.
+#[inline]
+pub fn expand_ident(input: TokenStream2) -> syn::Result<Ident> {
+    let (span, output, context) = expand_block(input)?;
+
+    // NOTE: Unlike a literal, an identifier cannot be wrapped into a block expression.
+    if let Some(dependency_span) = context.dependency_span() {
+        return Err(Error::new(
+            dependency_span,
+            "this transformation reads a file or an environment variable, which cannot be tracked within a single identifier\n\
+             consider `embed!` instead, e.g. `embed! { fn [< .. >]() {} }`",
+        ));
+    }
+
+    syn::parse2(output.clone()).map_err(|_| {
+        Error::new(
+            span,
+            format!(
+                "expected the block to expand to a single identifier, found `{output}`\n\
+                 consider appending `:concatenate` to the block"
+            ),
+        )
+    })
+}
+
+/// Expand exactly one block, returning the span of the block along with its expansion and the context it was expanded in.
+fn expand_block(input: TokenStream2) -> syn::Result<(Span, TokenStream2, Context)> {
+    let span = input
+        .clone()
+        .into_iter()
+        .next()
+        .map_or_else(Span::call_site, |tree| tree.span());
+
+    let embed = syn::parse2::<Embed>(input)?;
+
+    if !embed.is_block() {
+        return Err(Error::new(span, "expected exactly one block, e.g. `[< hello world >]:concatenate`"));
+    }
+
+    let mut context = Context::default();
+
+    let output = embed.expand(&mut context)?;
+
+    Ok((span, output, context))
+}
@@ -0,0 +1,35 @@
+//! The predicates read the process environment, thus this test stays alone within its own binary,
+//! so that changing the environment cannot race with any other test.
+
+use std::str::FromStr as _;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+fn expand(source: &str) -> Result<String, String> {
+    permafrost_core::expand(TokenStream2::from_str(source).expect("valid tokens"))
+        .map(|output| output.to_string())
+        .map_err(|err| err.to_string())
+}
+
+#[test]
+fn cfg_follows_forwarded_environment() {
+    let source = "struct [< Foo [< _serde >]:when{feature{\"serde\"}} [< _x86 >]:when{[< :target_arch{\"x86\"} >]} >]:concatenate;";
+
+    std::env::remove_var("CARGO_CFG_TARGET_ARCH");
+
+    assert!(expand(source)
+        .expect_err("the environment is not forwarded")
+        .contains("`CARGO_CFG_TARGET_ARCH` is not set"));
+
+    std::env::set_var("CARGO_CFG_TARGET_ARCH", "x86");
+
+    assert_eq!(expand(source), Ok("struct Foo_x86 ;".to_owned()));
+
+    std::env::set_var("CARGO_FEATURE_SERDE", "1");
+
+    assert_eq!(expand(source), Ok("struct Foo_serde_x86 ;".to_owned()));
+
+    // NOTE: Predicates are not tracked, thus these are valid in expression position as well.
+    assert_eq!(expand("[< :feature{\"serde\"} >]"), Ok("true".to_owned()));
+    assert_eq!(expand("[< :target_arch{\"x86_64\"} >]"), Ok("false".to_owned()));
+}
@@ -0,0 +1,196 @@
+use std::str::FromStr as _;
+
+use proc_macro2::{Delimiter, Group, TokenStream as TokenStream2, TokenTree as TokenTree2};
+
+/// Lex the source, as `quote!` would interpolate the bindings.
+fn tokens(source: &str) -> TokenStream2 {
+    TokenStream2::from_str(source).expect("valid tokens")
+}
+
+/// Expand the source, returning the output or the error message.
+fn expand(source: &str) -> Result<String, String> {
+    permafrost_core::expand(tokens(source))
+        .map(|output| output.to_string())
+        .map_err(|err| err.to_string())
+}
+
+#[test]
+fn regular_for_loop_is_kept() {
+    let source = "fn f() { for (i, x) in v.iter().enumerate() {} }";
+
+    assert_eq!(expand(source), Ok(tokens(source).to_string()));
+}
+
+#[test]
+fn indexed_loop_is_expanded() {
+    assert_eq!(
+        expand("for (#index, #item) in [< a b >] { #index #item }"),
+        Ok(tokens("0 a 1 b").to_string())
+    );
+}
+
+#[test]
+fn regular_if_with_block_is_kept() {
+    assert_eq!(
+        expand("fn f() { if [< is_ ready >]:concatenate() { g() } }"),
+        Ok(tokens("fn f() { if is_ready() { g() } }").to_string())
+    );
+
+    assert_eq!(
+        expand("fn f() { if [< FLAG_ READY >]:concatenate { g() } }"),
+        Ok(tokens("fn f() { if FLAG_READY { g() } }").to_string())
+    );
+}
+
+#[test]
+fn conditional_is_expanded() {
+    assert_eq!(
+        expand("let #count = [< a b c >]:count; #if #count > 2 { many } #else { few } #if [< true >] { yes }"),
+        Ok(tokens("many yes").to_string())
+    );
+    assert_eq!(
+        expand("#if [< a >] == [< b >] { one } #else if [< a >] != [< b >] { two } #else { three }"),
+        Ok(tokens("two").to_string())
+    );
+}
+
+#[test]
+fn regular_if_with_single_block_is_kept() {
+    assert_eq!(
+        expand("fn f() -> u8 { if [< x >] { 1 } else { 2 } }"),
+        Ok(tokens("fn f() -> u8 { if x { 1 } else { 2 } }").to_string())
+    );
+}
+
+#[test]
+fn regular_if_with_trailing_chain_is_kept() {
+    assert_eq!(
+        expand("fn f(x: u8) -> u8 { if x == [< a b >]:concatenate { 1 } else { 2 } }"),
+        Ok(tokens("fn f(x: u8) -> u8 { if x == ab { 1 } else { 2 } }").to_string())
+    );
+    assert_eq!(
+        expand("fn f() { while [< is_ ready >]:concatenate { g() } match [< a b >]:concatenate { _ => {} } }"),
+        Ok(tokens("fn f() { while is_ready { g() } match ab { _ => {} } }").to_string())
+    );
+    assert_eq!(
+        expand("fn f() { for x in [< a b >]:concatenate { g(x) } }"),
+        Ok(tokens("fn f() { for x in ab { g(x) } }").to_string())
+    );
+}
+
+#[test]
+fn regular_if_keeps_chain_arguments() {
+    assert_eq!(
+        expand("fn f() { if [< Is Ready >]:concatenate{ident}:case{snake} { g() } }"),
+        Ok(tokens("fn f() { if is_ready { g() } }").to_string())
+    );
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_non_finite_floats() {
+    let output = expand("m! { [< :toml{\"tests/data/floats.toml\", \"x\"} >] }").expect("non-finite floats expand");
+
+    assert!(output.starts_with(
+        &tokens(
+            "m! { ::core::primitive::f64::INFINITY ::core::primitive::f64::NEG_INFINITY \
+             ::core::primitive::f64::NAN -1.5 }"
+        )
+        .to_string()
+    ));
+}
+
+#[test]
+fn csv_cells() {
+    let output = expand("m! { [< :csv{\"tests/data/cells.csv\", ident literal} >] }").expect("cells expand");
+
+    assert!(output.starts_with(&tokens("m! { (r#type -2 \"a, b\") (value 3 \"c\") }").to_string()));
+}
+
+#[test]
+fn csv_empty_quoted_row() {
+    let output = expand("m! { [< :csv{\"tests/data/quoted.csv\"} >] }").expect("rows expand");
+
+    assert!(output.starts_with(&tokens("m! { (\"\") (\"last\") }").to_string()));
+}
+
+#[test]
+fn tracked_items() {
+    assert_eq!(
+        expand("const PREFIX: &str = [< :env{PERMAFROST_UNSET, \"my\"} >];"),
+        Ok(tokens("const PREFIX: &str = { const _: () = { let _ = option_env!(\"PERMAFROST_UNSET\"); }; \"my\" };").to_string())
+    );
+}
+
+#[test]
+fn tracked_functions() {
+    assert_eq!(
+        expand("fn [< get_ [< :env{PERMAFROST_UNSET, \"my\"} >] >]:concatenate{ident}(&self) { g() }"),
+        Ok(tokens("fn get_my(&self) { const _: () = { let _ = option_env!(\"PERMAFROST_UNSET\"); }; g() }").to_string())
+    );
+}
+
+#[test]
+fn tracked_expressions() {
+    assert_eq!(
+        expand("[< :env{PERMAFROST_UNSET, \"my\"} >]"),
+        Ok(tokens("{ const _: () = { let _ = option_env!(\"PERMAFROST_UNSET\"); }; \"my\" }").to_string())
+    );
+}
+
+#[test]
+fn reflect_fragment() {
+    // NOTE: A `$item:item` fragment reaches `embed!` wrapped in an invisible group.
+    let item = Group::new(
+        Delimiter::None,
+        tokens("struct Wrapper<T: Clone>(T) where T: Default;"),
+    );
+
+    for (transform, expected) in [
+        ("name", "Wrapper"),
+        ("generics", "<T: Clone>"),
+        ("generics{type}", "<T>"),
+        ("generics{where}", "where T: Default"),
+        ("fields", "(0 T)"),
+    ] {
+        let mut block = tokens("<");
+
+        block.extend([TokenTree2::Group(item.clone())]);
+        block.extend(tokens(&format!(":{transform} >")));
+
+        let source = TokenTree2::Group(Group::new(Delimiter::Bracket, block));
+
+        assert_eq!(
+            permafrost_core::expand(source.into()).map(|output| output.to_string()).map_err(|err| err.to_string()),
+            Ok(tokens(expected).to_string())
+        );
+    }
+}
+
+#[test]
+fn single_blocks() {
+    assert_eq!(
+        permafrost_core::expand_str(tokens("[< foo _ bar >]:concatenate{string}")).map(|output| output.to_string()).ok(),
+        Some("r#\"foo_bar\"#".to_owned())
+    );
+    assert_eq!(
+        permafrost_core::expand_str(tokens("[< :env{PERMAFROST_UNSET, \"my\"} >]")).map(|output| output.to_string()).ok(),
+        Some(tokens("{ const _: () = { let _ = option_env!(\"PERMAFROST_UNSET\"); }; \"my\" }").to_string())
+    );
+    assert_eq!(
+        permafrost_core::expand_ident(tokens("[< foo _ bar >]:concatenate")).map(|ident| ident.to_string()).ok(),
+        Some("foo_bar".to_owned())
+    );
+
+    let error = permafrost_core::expand_ident(tokens("[< :env{PERMAFROST_UNSET, \"my\"} >]:concatenate"))
+        .expect_err("an identifier cannot be tracked");
+
+    assert!(error.to_string().contains("cannot be tracked within a single identifier"));
+}
+
+#[test]
+fn operands_require_bindings() {
+    assert!(expand("for #item in #missing { #item }")
+        .expect_err("undeclared list")
+        .starts_with("unknown binding: `#missing`"));
+}
@@ -0,0 +1,42 @@
+use std::str::FromStr as _;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+fn expand(source: &str) -> Result<String, String> {
+    permafrost_core::expand(TokenStream2::from_str(source).expect("valid tokens"))
+        .map(|output| output.to_string())
+        .map_err(|err| err.to_string())
+}
+
+#[test]
+fn raw_identifiers_are_unraw() {
+    let output = expand("[< r#type:gensym >]").expect("valid identifier");
+
+    assert!(output.starts_with("__permafrost_type_"), "{output}");
+
+    let output = expand("[< r#type _ x >]:gensym").expect("valid identifier");
+
+    assert!(output.starts_with("__permafrost_type_x_"), "{output}");
+}
+
+#[test]
+fn invalid_identifiers_are_an_error() {
+    assert_eq!(
+        expand("[< 1 a >]:gensym"),
+        Err("`1a` is not a valid identifier".to_owned())
+    );
+    assert_eq!(
+        expand("[< 1 a >]:concatenate"),
+        Err("`1a` is not a valid identifier".to_owned())
+    );
+    assert_eq!(
+        expand("[< self >]:concatenate{r#ident}"),
+        Err("`r#self` is not a valid identifier".to_owned())
+    );
+}
+
+#[test]
+fn raw_identifiers_concatenate() {
+    assert_eq!(expand("[< r#type _ x >]:concatenate"), Ok("type_x".to_owned()));
+    assert_eq!(expand("[< r#type >]:concatenate{r#ident}"), Ok("r#type".to_owned()));
+}
@@ -0,0 +1,55 @@
+use std::str::FromStr as _;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+fn expand(source: &str) -> Result<String, String> {
+    permafrost_core::expand(TokenStream2::from_str(source).expect("valid tokens"))
+        .map(|output| output.to_string())
+        .map_err(|err| err.to_string())
+}
+
+#[test]
+fn unknown_delimiter_is_an_error() {
+    let error = expand("[< (hello) >]:ungroup{angle}").expect_err("unknown delimiter");
+
+    assert!(error.starts_with("unknown delimiter: `angle`"));
+}
+
+#[test]
+fn unknown_option_is_an_error() {
+    let error = expand("[< (hello) >]:flatten{level = 1}").expect_err("unknown option");
+
+    assert!(error.starts_with("unknown option: `level`"));
+}
+
+#[test]
+fn malformed_flatten_arguments_are_an_error() {
+    for source in [
+        "[< (hello) >]:flatten{3}",
+        "[< (hello) >]:flatten{type}",
+        "[< (hello) >]:flatten{depth = 1,,}",
+        "[< (hello) >]:flatten{paren,, bracket}",
+    ] {
+        assert!(expand(source).is_err(), "`{source}` should be rejected");
+    }
+
+    let error = expand("[< (hello) >]:flatten{3}").expect_err("literal argument");
+
+    assert_eq!(error, "expected a delimiter or `depth = n`");
+}
+
+#[test]
+fn malformed_ungroup_arguments_are_an_error() {
+    for source in ["[< (hello) >]:ungroup{3}", "[< (hello) >]:ungroup{type}", "[< (hello) >]:ungroup{paren,,}"] {
+        assert!(expand(source).is_err(), "`{source}` should be rejected");
+    }
+}
+
+#[test]
+fn separated_arguments() {
+    assert_eq!(
+        expand("[< ([hello] {world}) >]:flatten{paren, bracket depth = 2,}"),
+        Ok("hello { world }".to_owned())
+    );
+    assert_eq!(expand("[< (hello) [world] >]:ungroup{paren bracket,}"), Ok("hello world".to_owned()));
+}
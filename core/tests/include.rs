@@ -0,0 +1,98 @@
+use std::str::FromStr as _;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+fn tokens(source: &str) -> TokenStream2 {
+    TokenStream2::from_str(source).expect("valid tokens")
+}
+
+fn expand(source: &str) -> Result<String, String> {
+    permafrost_core::expand(tokens(source))
+        .map(|output| output.to_string())
+        .map_err(|err| err.to_string())
+}
+
+/// The constant tracking a file within `tests/data`.
+fn tracking(name: &str) -> String {
+    format!(
+        "const _: () = {{ let _ = include_bytes!({:?}); }};",
+        format!("{}/tests/data/{name}", env!("CARGO_MANIFEST_DIR"))
+    )
+}
+
+#[test]
+fn include_string() {
+    assert_eq!(
+        expand("const NAMES: &str = [< :include{\"tests/data/names.txt\"} >];"),
+        Ok(tokens(&format!("const NAMES: &str = {{ {} \"alpha\\nbeta\\n\" }};", tracking("names.txt"))).to_string())
+    );
+}
+
+#[test]
+fn include_tokens() {
+    assert_eq!(
+        expand("static TABLE: &[u8] = &[< :include{\"tests/data/table.rs\", tokens} >];"),
+        Ok(tokens(&format!("static TABLE: &[u8] = {{ {} &[1, 2, 3] }};", tracking("table.rs"))).to_string())
+    );
+}
+
+#[test]
+fn tracked_in_first_body() {
+    assert_eq!(
+        expand("struct Names; fn names(&self) -> &str { [< :include{\"tests/data/names.txt\"} >] } fn other() {}"),
+        Ok(tokens(&format!(
+            "struct Names; fn names(&self) -> &str {{ {} \"alpha\\nbeta\\n\" }} fn other() {{}}",
+            tracking("names.txt")
+        ))
+        .to_string())
+    );
+}
+
+#[test]
+fn tracked_after_items() {
+    assert_eq!(
+        expand("struct Table([u8; [< :include{\"tests/data/table.rs\", tokens}:count >]]);"),
+        Ok(tokens(&format!("struct Table([u8; 1]); {}", tracking("table.rs"))).to_string())
+    );
+}
+
+#[test]
+fn tracked_expression() {
+    assert_eq!(
+        expand("[< :include{\"tests/data/names.txt\"} >]"),
+        Ok(tokens(&format!("{{ {} \"alpha\\nbeta\\n\" }}", tracking("names.txt"))).to_string())
+    );
+}
+
+#[test]
+fn tracked_statements() {
+    assert_eq!(
+        expand("let names = [< :include{\"tests/data/names.txt\"} >]; println!(\"{names}\");"),
+        Ok(tokens(&format!(
+            "{} let names = \"alpha\\nbeta\\n\"; println!(\"{{names}}\");",
+            tracking("names.txt")
+        ))
+        .to_string())
+    );
+}
+
+#[test]
+fn untrackable_expansion_is_an_error() {
+    let error = expand("struct [< :include{\"tests/data/names.txt\"} >]").expect_err("neither items nor an expression");
+
+    assert!(error.contains("neither a list of items, an expression nor a list of statements"));
+}
+
+#[test]
+fn missing_file_is_an_error() {
+    let error = expand("[< :include{\"tests/data/missing.txt\"} >]").expect_err("missing file");
+
+    assert!(error.contains("failed to read"));
+}
+
+#[test]
+fn unknown_mode_is_an_error() {
+    let error = expand("[< :include{\"tests/data/names.txt\", bytes} >]").expect_err("unknown mode");
+
+    assert!(error.contains("unknown mode: `bytes`"));
+}
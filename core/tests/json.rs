@@ -0,0 +1,48 @@
+#![cfg(feature = "json")]
+
+use std::str::FromStr as _;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+fn tokens(source: &str) -> TokenStream2 {
+    TokenStream2::from_str(source).expect("valid tokens")
+}
+
+fn expand(source: &str) -> Result<String, String> {
+    permafrost_core::expand(tokens(source))
+        .map(|output| output.to_string())
+        .map_err(|err| err.to_string())
+}
+
+#[test]
+fn json_array() {
+    let output = expand("m! { const OPCODES: &[&str] = &[for #opcode in [< :json{\"tests/data/config.json\", \"opcodes\"} >] { #opcode, }]; }")
+        .expect("readable file");
+
+    assert!(output.starts_with(&tokens("m! { const OPCODES: &[&str] = &[\"Nop\", \"Push\", \"Pop\",]; }").to_string()));
+}
+
+#[test]
+fn json_query() {
+    let output = expand("m! { const DEPTH: i32 = [< :json{\"tests/data/config.json\", \"limits.depth\"} >]; }").expect("readable file");
+
+    assert!(output.starts_with(&tokens("m! { const DEPTH: i32 = 8; }").to_string()));
+
+    let output = expand("m! { const ENABLED: bool = [< :json{\"tests/data/config.json\", \"enabled\"} >]; }").expect("readable file");
+
+    assert!(output.starts_with(&tokens("m! { const ENABLED: bool = true; }").to_string()));
+}
+
+#[test]
+fn json_object() {
+    let output = expand("m! { const LIMITS: &str = stringify!([< :json{\"tests/data/config.json\", \"limits\"} >]); }").expect("readable file");
+
+    assert!(output.replace(' ', "").starts_with("m!{constLIMITS:&str=stringify!((\"depth\"8)(\"offset\"-2));}"));
+}
+
+#[test]
+fn json_unmatched_query() {
+    let error = expand("[< :json{\"tests/data/config.json\", \"opcodes[3]\"} >]").expect_err("unmatched query");
+
+    assert!(error.contains("does not match any value"));
+}
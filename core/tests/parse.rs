@@ -0,0 +1,40 @@
+use std::str::FromStr as _;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+fn expand(source: &str) -> Result<String, String> {
+    permafrost_core::expand(TokenStream2::from_str(source).expect("valid tokens"))
+        .map(|output| output.to_string().replace(' ', ""))
+        .map_err(|err| err.to_string())
+}
+
+#[test]
+fn parse_keeps_valid_tokens() {
+    assert_eq!(
+        expand("type Buffer = [< \"Vec<u8>\":unstringify:parse{ty} >];"),
+        Ok("typeBuffer=Vec<u8>;".to_owned())
+    );
+    assert_eq!(
+        expand("const VALUE: u8 = [< \"1 + 2\":unstringify:parse{expr} >];"),
+        Ok("constVALUE:u8=1+2;".to_owned())
+    );
+}
+
+#[test]
+fn parse_rejects_malformed_tokens() {
+    let error = expand("type Broken = [< \"Vec<u8\":unstringify:parse{ty} >];").expect_err("malformed type");
+
+    assert!(error.starts_with("expected a type, found `Vec < u8`"));
+}
+
+#[test]
+fn parse_rejects_trailing_tokens() {
+    let error = expand("[< \"value extra\":unstringify:parse{ident} >]").expect_err("trailing tokens");
+
+    assert!(error.contains("unexpected trailing tokens"));
+}
+
+#[test]
+fn parse_rejects_unknown_fragments() {
+    assert!(expand("[< value:parse{nothing} >]").is_err());
+}
@@ -0,0 +1,69 @@
+use std::str::FromStr as _;
+
+use permafrost_core::{Registry, Transformer};
+use proc_macro2::{Ident, TokenStream as TokenStream2, TokenTree as TokenTree2};
+
+/// Prefix every identifier with the given arguments.
+struct Namespaced;
+
+impl Transformer for Namespaced {
+    fn apply(&self, input: TokenStream2, args: TokenStream2) -> syn::Result<TokenStream2> {
+        Ok(input
+            .into_iter()
+            .map(|target_tree| match target_tree {
+                TokenTree2::Ident(ident) => {
+                    TokenTree2::Ident(Ident::new(&format!("{args}_{ident}"), ident.span()))
+                }
+                target_tree => target_tree,
+            })
+            .collect())
+    }
+}
+
+fn tokens(source: &str) -> TokenStream2 {
+    TokenStream2::from_str(source).expect("valid tokens")
+}
+
+#[test]
+fn registered_transformer_is_applied() {
+    let registry = Registry::default().register("namespaced", Namespaced);
+
+    let output = permafrost_core::expand_with(
+        tokens("fn [< value >]:namespaced{[< my >]:case{upper}}() {}"),
+        registry,
+    )
+    .expect("registered transformer");
+
+    assert_eq!(output.to_string(), tokens("fn MY_value() {}").to_string());
+}
+
+#[test]
+fn builtin_transformers_are_available() {
+    let registry = Registry::default().register("namespaced", Namespaced);
+
+    let output = permafrost_core::expand_with(tokens("[< a b >]:namespaced{x}:concatenate"), registry)
+        .expect("built-in transformers");
+
+    assert_eq!(output.to_string(), "x_ax_b");
+}
+
+#[test]
+fn unregistered_transformer_is_an_error() {
+    let error = permafrost_core::expand(tokens("[< value >]:namespaced")).expect_err("not registered");
+
+    assert!(error.to_string().contains("unrecognized transform: `namespaced`"));
+}
+
+#[test]
+#[should_panic(expected = "shadows a built-in transform")]
+fn builtin_cannot_be_shadowed() {
+    let _ = Registry::default().register("case", Namespaced);
+}
+
+#[test]
+#[should_panic(expected = "is already registered")]
+fn name_cannot_be_registered_twice() {
+    let _ = Registry::default()
+        .register("namespaced", Namespaced)
+        .register("namespaced", Namespaced);
+}
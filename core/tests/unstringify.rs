@@ -0,0 +1,30 @@
+use std::str::FromStr as _;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+fn expand(source: &str) -> Result<String, String> {
+    permafrost_core::expand(TokenStream2::from_str(source).expect("valid tokens"))
+        .map(|output| output.to_string())
+        .map_err(|err| err.to_string())
+}
+
+#[test]
+fn invalid_utf8_is_an_error() {
+    let error = expand("[< b\"\\xFF\" >]:unstringify").expect_err("invalid UTF-8");
+
+    assert!(error.contains("not valid UTF-8"));
+}
+
+#[test]
+fn unlexable_contents_are_an_error() {
+    let error = expand("[< \"(unclosed\" >]:unstringify").expect_err("unbalanced delimiter");
+
+    assert!(error.starts_with("failed to unstringify `(unclosed`"));
+}
+
+#[test]
+fn unknown_mode_is_an_error() {
+    let error = expand("[< \"hello\" >]:unstringify{deep}").expect_err("unknown mode");
+
+    assert!(error.starts_with("unknown mode: `deep`"));
+}
@@ -1,7 +0,0 @@
-mod transformate;
-mod kind;
-mod segment;
-
-pub use segment::Segment;
-pub use kind::TransformChain;
-pub use transformate::Transformate;
\ No newline at end of file
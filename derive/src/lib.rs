@@ -2,10 +2,7 @@
 
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-
-pub(crate) mod embed;
-
-use embed::Embed;
+use quote::ToTokens as _;
 
 
 /// Embed a sequence of tokens.
@@ -27,7 +24,81 @@ use embed::Embed;
 pub fn embed(input: TokenStream) -> TokenStream {
     let input = TokenStream2::from(input);
 
-    Embed::recursively_expand(input)
+    permafrost_core::expand(input)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
+/// Embed the annotated item.
+///
+/// This is the attribute form of [`embed!`], the item is expanded by the same engine, while remaining regular code
+/// for rustfmt and rust-analyzer. The arguments of the attribute, if any, are expanded right before the item,
+/// thus these can hold declarations, such as bindings and aliases, shared by the whole item.
+///
+/// As the name `embed` is already taken by the function-like macro, the attribute is named `embedded`.
+///
+/// Note that the item is parsed by the compiler before this attribute is applied, thus it must be valid Rust.
+/// Blocks can only appear where arbitrary tokens are accepted, such as within macro invocations and attribute arguments.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// use permafrost_derive::embedded;
+///
+/// #[embedded(let #name = [< foo bar >]:case{pascal}:concatenate;)]
+/// fn describe() -> &'static str {
+///     stringify!(#name) // Expands to `stringify!(FooBar)`.
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn embedded(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut input = TokenStream2::from(args);
+
+    input.extend(TokenStream2::from(item));
+
+    permafrost_core::expand(input)
         .unwrap_or_else(|err| err.into_compile_error())
         .into()
 }
+
+/// Embed a single block, expanding to a string literal.
+///
+/// Unlike [`embed!`], the input must be exactly one block, which must expand to a single string literal.
+/// If the block reads a file or an environment variable, the literal is wrapped into a block expression tracking it,
+/// thus it can no longer be passed to macros expecting a literal, such as `concat!`.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// use permafrost_derive::embed_str;
+///
+/// const NAME: &str = embed_str!([< foo bar >]:case{pascal}:concatenate{string}); // Expands to `"FooBar"`.
+/// ```
+#[proc_macro]
+pub fn embed_str(input: TokenStream) -> TokenStream {
+    let input = TokenStream2::from(input);
+
+    permafrost_core::expand_str(input)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
+/// Embed a single block, expanding to an identifier.
+///
+/// Unlike [`embed!`], the input must be exactly one block, which must expand to a single identifier.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// use permafrost_derive::embed_ident;
+///
+/// let embed_ident!([< value _ $index >]:concatenate) = 1; // Expands to `let value_0 = 1;`, given `$index` is `0`.
+/// ```
+#[proc_macro]
+pub fn embed_ident(input: TokenStream) -> TokenStream {
+    let input = TokenStream2::from(input);
+
+    permafrost_core::expand_ident(input)
+        .map_or_else(|err| err.into_compile_error(), |output| output.into_token_stream())
+        .into()
+}
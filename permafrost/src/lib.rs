@@ -1,4 +1,54 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 
-pub use permafrost_derive::embed;
+pub use permafrost_derive::{embed, embed_ident, embed_str, embedded};
+
+/// Define a wrapper around [`embed!`], with a shared set of declarations prepended to every invocation.
+///
+/// As procedural macros cannot share state between invocations, this generates a `macro_rules!` macro
+/// that forwards its input to [`embed!`], right after the given declarations.
+/// Any aliases and bindings declared this way are thus available to every invocation of the wrapper.
+///
+/// Attributes, such as `#[macro_export]`, are applied to the generated macro.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// permafrost::define_transforms! {
+///     macro my_embed;
+///
+///     transform snake_ident = flatten:case{snake}:concatenate;
+/// }
+///
+/// my_embed! {
+///     fn [< GetValue >]:snake_ident() {} // Expands to `fn get_value() {}`.
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_transforms {
+    (
+        $(#[$meta:meta])*
+        macro $name:ident;
+
+        $($declarations:tt)*
+    ) => {
+        $crate::define_transforms! {
+            @define ($) $(#[$meta])* $name { $($declarations)* }
+        }
+    };
+    // NOTE: The `$` is passed along as a token, as a `macro_rules!` macro cannot emit it directly.
+    (
+        @define ($dollar:tt) $(#[$meta:meta])* $name:ident { $($declarations:tt)* }
+    ) => {
+        $(#[$meta])*
+        macro_rules! $name {
+            ($dollar($dollar input:tt)*) => {
+                $crate::embed! {
+                    $($declarations)*
+
+                    $dollar($dollar input)*
+                }
+            };
+        }
+    };
+}
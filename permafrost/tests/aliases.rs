@@ -0,0 +1,25 @@
+use permafrost::embed;
+
+#[test]
+fn alias_applies_its_chain() {
+    embed! {
+        transform pascal_ident = flatten:case{pascal}:concatenate;
+
+        const GETTER: &str = stringify!([< (get) [value] >]:pascal_ident);
+        const SETTER: &str = stringify!([< set value >]:pascal_ident);
+    }
+
+    assert_eq!((GETTER, SETTER), ("GetValue", "SetValue"));
+}
+
+#[test]
+fn alias_builds_on_another_alias() {
+    embed! {
+        transform pascal_ident = case{pascal}:concatenate;
+        transform getter = prefix{get}:pascal_ident;
+
+        const GETTER: &str = stringify!([< value >]:getter);
+    }
+
+    assert_eq!(GETTER, "GetValue");
+}
@@ -0,0 +1,20 @@
+use permafrost::embed;
+
+#[test]
+fn arguments_are_expanded() {
+    embed! {
+        const APPENDED: &str = stringify!([< foo >]:append{[< bar >]:case{upper}}:concatenate);
+        const PREFIXED: &str = stringify!([< c >]:prefix{[< a b >]:concatenate}:concatenate);
+    }
+
+    assert_eq!((APPENDED, PREFIXED), ("fooBAR", "abc"));
+}
+
+#[test]
+fn arguments_are_expanded_after_input() {
+    embed! {
+        const NESTED: &str = stringify!([< [< x >]:case{upper} y >]:append{[< z >]:case{upper}}:concatenate);
+    }
+
+    assert_eq!(NESTED, "XyZ");
+}
@@ -0,0 +1,52 @@
+use permafrost::embed;
+
+embed! {
+    let #builder = [< foo Builder >]:case{pascal}:concatenate;
+
+    struct #builder;
+
+    impl #builder {
+        const NAME: &'static str = [< #builder >]:stringify;
+    }
+}
+
+#[test]
+fn binding_is_reused() {
+    assert_eq!(FooBuilder::NAME, "FooBuilder");
+}
+
+#[test]
+fn binding_is_visible_in_arguments() {
+    embed! {
+        let #suffix = [< bar >]:case{upper};
+
+        const APPENDED: &str = stringify!([< foo >]:append{#suffix}:concatenate);
+    }
+
+    assert_eq!(APPENDED, "fooBAR");
+}
+
+#[test]
+fn undeclared_references_are_kept() {
+    embed! {
+        let #name = [< foo >]:case{pascal};
+
+        const QUOTED: &str = stringify!(quote! { #name(#value) });
+    }
+
+    assert_eq!(QUOTED.replace(' ', ""), "quote!{Foo(#value)}");
+}
+
+#[test]
+fn bindings_are_scoped_to_groups() {
+    embed! {
+        const SCOPED: &str = stringify!({ let #name = [< a >]; #name } #name);
+
+        let #name = [< b >];
+
+        const OUTER: &str = stringify!((#name) #name);
+    }
+
+    assert_eq!(SCOPED.replace(' ', ""), "{a}#name");
+    assert_eq!(OUTER.replace(' ', ""), "(b)b");
+}
@@ -0,0 +1,22 @@
+use permafrost::embed;
+
+#[test]
+fn case_keeps_group_delimiters() {
+    embed! {
+        const GROUPS: &str = stringify!([< (foo_bar [baz_qux]) >]:case{pascal});
+    }
+
+    assert_eq!(GROUPS, "(FooBar [BazQux])");
+}
+
+embed! {
+    #[allow(dead_code)]
+    struct [< r#match:case{pascal} >];
+
+    const RAW: &str = stringify!([< r#match:case{pascal} r#Type:case{snake} r#SELF:case{lower} >]);
+}
+
+#[test]
+fn raw_identifiers_keep_their_prefix() {
+    assert_eq!(RAW, "r#Match r#type self");
+}
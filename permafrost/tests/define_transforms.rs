@@ -0,0 +1,23 @@
+permafrost::define_transforms! {
+    macro my_embed;
+
+    transform snake_ident = flatten:case{snake}:concatenate;
+
+    let #prefix = [< get >];
+}
+
+my_embed! {
+    fn [< GetValue >]:snake_ident() -> u8 {
+        1
+    }
+}
+
+#[test]
+fn declarations_are_shared() {
+    my_embed! {
+        const GETTER: &str = stringify!([< #prefix value >]:case{pascal}:concatenate);
+    }
+
+    assert_eq!(get_value(), 1);
+    assert_eq!(GETTER, "GetValue");
+}
@@ -0,0 +1,26 @@
+use permafrost::embedded;
+
+// NOTE: The item is parsed by the compiler first, thus blocks only appear where arbitrary tokens are accepted.
+#[embedded(let #name = [< foo bar >]:case{pascal}:concatenate;)]
+fn describe() -> &'static str {
+    stringify!(#name)
+}
+
+struct Greeter;
+
+#[embedded(transform shout = case{upper}:concatenate{string};)]
+impl Greeter {
+    fn greet(&self) -> &'static str {
+        concat!([< hello >]:shout, ", ", [< world >]:shout)
+    }
+}
+
+#[test]
+fn arguments_are_shared_by_the_item() {
+    assert_eq!(describe(), "FooBar");
+}
+
+#[test]
+fn blocks_within_macro_invocations() {
+    assert_eq!(Greeter.greet(), "HELLO, WORLD");
+}
@@ -0,0 +1,46 @@
+use permafrost::embed;
+
+trait Named {
+    const NAME: &'static str;
+}
+
+struct Package;
+
+embed! {
+    impl Named for Package {
+        const NAME: &'static str = [< :env{CARGO_PKG_NAME} >];
+    }
+}
+
+#[test]
+fn env_within_trait_impls() {
+    assert_eq!(Package::NAME, "permafrost");
+}
+
+#[test]
+fn env_within_expressions() {
+    let name = embed! { [< :env{"CARGO_PKG_NAME"} >] };
+
+    assert_eq!(name, "permafrost");
+    assert_eq!(embed! { [< :env{PERMAFROST_UNSET, "my"} >] }, "my");
+}
+
+#[test]
+fn env_within_statements() {
+    embed! {
+        let prefix = [< :env{PERMAFROST_UNSET, "my"} >];
+    }
+
+    assert_eq!(prefix, "my");
+}
+
+#[test]
+fn env_within_identifiers() {
+    embed! {
+        fn [< get_ [< :env{PERMAFROST_UNSET, "prefix"} >] >]:concatenate{ident}() -> u8 {
+            1
+        }
+    }
+
+    assert_eq!(get_prefix(), 1);
+}
@@ -0,0 +1,27 @@
+use permafrost::embed;
+
+trait Wide {
+    type Output;
+}
+
+impl Wide for u8 {
+    type Output = u16;
+}
+
+#[test]
+fn escaped_slice_type() {
+    embed! {
+        let values: &[<< <u8 as Wide>::Output >>] = &[1, 2];
+    }
+
+    assert_eq!(values, &[1u16, 2]);
+}
+
+#[test]
+fn escaped_contents_are_not_expanded() {
+    embed! {
+        const ESCAPED: &str = stringify!([<< [< a b >]:concatenate >>]);
+    }
+
+    assert_eq!(ESCAPED.replace(' ', ""), "[[<ab>]:concatenate]");
+}
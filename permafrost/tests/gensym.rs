@@ -0,0 +1,44 @@
+use permafrost::embed;
+
+macro_rules! checked {
+    ($name:ident) => {
+        embed! {
+            #[allow(dead_code)]
+            fn [< checks $name >]:gensym() -> &'static str {
+                stringify!($name)
+            }
+        }
+    };
+}
+
+// NOTE: Both expand to a function, which would conflict without a distinct hash.
+checked!(foo);
+checked!(foo);
+
+#[test]
+fn gensym_is_prefixed() {
+    embed! {
+        const NAME: &str = stringify!([< foo bar >]:gensym);
+    }
+
+    assert!(NAME.starts_with("__permafrost_foobar_"), "{NAME}");
+    assert_eq!(NAME.len(), "__permafrost_foobar_".len() + 8);
+}
+
+#[test]
+fn gensym_is_stable_within_an_invocation() {
+    embed! {
+        let [< value >]:gensym = 1;
+
+        assert_eq!([< value >]:gensym, 1);
+    }
+}
+
+#[test]
+fn gensym_accepts_raw_identifiers() {
+    embed! {
+        const RAW: &str = stringify!([< r#type:gensym >]);
+    }
+
+    assert!(RAW.starts_with("__permafrost_type_"));
+}
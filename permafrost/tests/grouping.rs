@@ -0,0 +1,36 @@
+use permafrost::embed;
+
+embed! {
+    const UNGROUPED: &str = stringify!([< (hello [world]) >]:ungroup);
+
+    const UNGROUPED_BRACKETS: &str = stringify!([< [hello] (world) >]:ungroup{bracket});
+
+    const UNGROUPED_NONE: &str = stringify!([< [hello] (world) >]:ungroup{none});
+
+    const FLATTENED: &str = stringify!([< (hello [world]) >]:flatten);
+
+    const FLATTENED_BRACKETS: &str = stringify!([< (hello [world]) >]:flatten{bracket});
+
+    const FLATTENED_DEPTH: &str = stringify!([< (hello ((world))) >]:flatten{depth = 2});
+
+    const FLATTENED_BOTH: &str = stringify!([< (hello [(world)]) >]:flatten{paren, depth = 1});
+}
+
+#[test]
+fn ungroup_delimiters() {
+    assert_eq!(UNGROUPED.replace(' ', ""), "hello[world]");
+    assert_eq!(UNGROUPED_BRACKETS.replace(' ', ""), "hello(world)");
+    assert_eq!(UNGROUPED_NONE.replace(' ', ""), "[hello](world)");
+}
+
+#[test]
+fn flatten_delimiters() {
+    assert_eq!(FLATTENED.replace(' ', ""), "helloworld");
+    assert_eq!(FLATTENED_BRACKETS.replace(' ', ""), "(helloworld)");
+}
+
+#[test]
+fn flatten_depth() {
+    assert_eq!(FLATTENED_DEPTH.replace(' ', ""), "hello(world)");
+    assert_eq!(FLATTENED_BOTH.replace(' ', ""), "hello[(world)]");
+}
@@ -0,0 +1,42 @@
+use permafrost::embed;
+
+macro_rules! offset {
+    ($name:ident, $value:expr) => {
+        embed! {
+            {
+                let [< $name _offset >]:concatenate:hygiene{mixed_site} = 1;
+
+                $value + [< $name _offset >]:concatenate:hygiene{mixed_site}
+            }
+        }
+    };
+}
+
+#[test]
+fn mixed_site_does_not_shadow() {
+    let x_offset = 10;
+
+    assert_eq!(offset!(x, x_offset), 11);
+}
+
+#[test]
+fn call_site_restores_resolution() {
+    embed! {
+        let [< value >]:hygiene{mixed_site}:hygiene{call_site} = 1;
+    }
+
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn def_site_resolves_at_mixed_site() {
+    let value = 1;
+
+    embed! {
+        let [< value >]:hygiene{def_site} = 2;
+
+        assert_eq!([< value >]:hygiene{mixed_site}, 2);
+    }
+
+    assert_eq!(value, 1);
+}
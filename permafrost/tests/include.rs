@@ -0,0 +1,47 @@
+use permafrost::embed;
+
+const NAMES: &str = "alpha\nbeta\n";
+
+trait Named {
+    fn names(&self) -> &'static str;
+}
+
+struct Inherent;
+
+struct Implemented;
+
+embed! {
+    impl Inherent {
+        const NAMES: &'static str = [< :include{"tests/data/names.txt"} >];
+    }
+}
+
+embed! {
+    impl Named for Implemented {
+        fn names(&self) -> &'static str {
+            [< :include{"tests/data/names.txt"} >]
+        }
+    }
+}
+
+#[test]
+fn tracked_within_impl_blocks() {
+    assert_eq!(Inherent::NAMES, NAMES);
+    assert_eq!(Implemented.names(), NAMES);
+}
+
+#[test]
+fn tracked_within_expressions() {
+    let names = embed! { [< :include{"tests/data/names.txt"} >] };
+
+    assert_eq!(names, NAMES);
+}
+
+#[test]
+fn tracked_within_statements() {
+    embed! {
+        let names = [< :include{"tests/data/names.txt"} >];
+    }
+
+    assert_eq!(names, NAMES);
+}
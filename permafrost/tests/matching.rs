@@ -0,0 +1,22 @@
+use permafrost::embed;
+
+macro_rules! describe {
+    ($($tt:tt)*) => {
+        embed! {
+            [< $($tt)* >]:match{
+                (#name:ident) => { [< "identifier " #name >]:concatenate{string} }
+                (#value:literal) => { [< "literal " #value:stringify >]:concatenate{string} }
+                ([#inner:ty]) => { [< "slice of " #inner:stringify >]:concatenate{string} }
+                _ => { "something else" }
+            }
+        }
+    };
+}
+
+#[test]
+fn first_matching_arm_is_expanded() {
+    assert_eq!(describe!(foo), "identifier foo");
+    assert_eq!(describe!(42), "literal 42");
+    assert_eq!(describe!([u8]), "slice of u8");
+    assert_eq!(describe!(a + b), "something else");
+}
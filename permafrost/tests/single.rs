@@ -0,0 +1,24 @@
+use permafrost::{embed_ident, embed_str};
+
+const NAME: &str = embed_str!([< foo bar >]:case{pascal}:concatenate{string});
+
+#[test]
+fn embed_str_literal() {
+    assert_eq!(NAME, "FooBar");
+    assert_eq!(concat!(embed_str!([< a b >]:concatenate{string}), "c"), "abc");
+}
+
+#[test]
+fn embed_str_tracks_reads() {
+    const NAMES: &str = embed_str!([< :include{"tests/data/names.txt"} >]);
+
+    assert_eq!(NAMES, "alpha\nbeta\n");
+    assert_eq!(embed_str!([< :env{CARGO_PKG_NAME} >]), "permafrost");
+}
+
+#[test]
+fn embed_ident_binding() {
+    let embed_ident!([< value _ 0 >]:concatenate) = 1;
+
+    assert_eq!(value_0, 1);
+}
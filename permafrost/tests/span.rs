@@ -0,0 +1,40 @@
+use permafrost::embed;
+
+#[test]
+fn span_keeps_tokens() {
+    embed! {
+        const FIRST: &str = stringify!([< hello world >]:span{first}:concatenate);
+        const LAST: &str = stringify!([< hello world >]:span{last}:concatenate);
+    }
+
+    assert_eq!((FIRST, LAST), ("helloworld", "helloworld"));
+}
+
+#[test]
+fn span_selects_resolution() {
+    let value = 1;
+
+    // NOTE: A mixed-site binding does not shadow the one written at the call site.
+    embed! {
+        let [< value >]:span{mixed_site} = 2;
+
+        assert_eq!([< value >]:span{mixed_site}, 2);
+    }
+
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn transformers_keep_spans() {
+    let value = 1;
+
+    // NOTE: Were the generated identifiers spanned at the call site, these would shadow `value`.
+    embed! {
+        let [< VALUE >]:span{mixed_site}:case{snake} = 2;
+        let [< va lue >]:span{mixed_site}:concatenate = 3;
+
+        assert_eq!([< value >]:span{mixed_site}, 3);
+    }
+
+    assert_eq!(value, 1);
+}
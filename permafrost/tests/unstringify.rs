@@ -0,0 +1,29 @@
+use permafrost::embed;
+
+embed! {
+    const STRINGS: &str = stringify!([< "hello" b"world" c"again" r"raw" >]:unstringify);
+
+    const SHALLOW: &str = stringify!([< ("hello") >]:unstringify);
+
+    const RECURSIVE: &str = stringify!([< ("hello" ["world"]) >]:unstringify{recursive});
+
+    fn [< "unstringified" >]:unstringify() -> u8 {
+        1
+    }
+}
+
+#[test]
+fn string_kinds() {
+    assert_eq!(STRINGS, "hello world again raw");
+}
+
+#[test]
+fn modes() {
+    assert_eq!(SHALLOW, "(\"hello\")");
+    assert_eq!(RECURSIVE, "(hello [world])");
+}
+
+#[test]
+fn unstringified_ident() {
+    assert_eq!(unstringified(), 1);
+}
@@ -0,0 +1,6 @@
+// NOTE: This is the build script recommended by the README, forwarding the build script environment to the crate.
+fn main() {
+    for (name, value) in std::env::vars().filter(|(name, _)| name.starts_with("CARGO_FEATURE_") || name.starts_with("CARGO_CFG_")) {
+        println!("cargo:rustc-env={name}={value}");
+    }
+}
@@ -0,0 +1,3 @@
+//! Checks that the feature and target predicates follow the environment forwarded by a build script.
+//!
+//! See `tests/forwarding.rs`.
@@ -0,0 +1,37 @@
+use permafrost::embed;
+
+embed! {
+    #if [< :feature{"serde"} >] {
+        const SERDE: bool = true;
+    } #else {
+        const SERDE: bool = false;
+    }
+
+    const UNUSED_FEATURE: bool = [< :feature{"unused-feature"} >];
+
+    const POINTER_WIDTH_64: bool = [< :target_pointer_width{64} >];
+
+    const UNIX: bool = [< :target_family{"unix"} >];
+}
+
+#[test]
+fn features() {
+    assert_eq!(SERDE, cfg!(feature = "serde"));
+    assert_eq!(UNUSED_FEATURE, cfg!(feature = "unused-feature"));
+}
+
+#[test]
+fn targets() {
+    assert_eq!(POINTER_WIDTH_64, cfg!(target_pointer_width = "64"));
+    assert_eq!(UNIX, cfg!(target_family = "unix"));
+}
+
+#[test]
+fn predicates_in_expressions() {
+    let serde: bool = embed! { [< :feature{"serde"} >] };
+
+    let pointer_width_64: bool = embed! { [< :target_pointer_width{64} >] };
+
+    assert_eq!(serde, cfg!(feature = "serde"));
+    assert_eq!(pointer_width_64, cfg!(target_pointer_width = "64"));
+}